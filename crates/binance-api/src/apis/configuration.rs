@@ -37,7 +37,9 @@ impl Default for Configuration {
     fn default() -> Self {
         Configuration {
             base_path: "https://api.binance.com".to_owned(),
-            user_agent: Some("OpenAPI-Generator/1.0/rust".to_owned()),
+            user_agent: Some(
+                concat!(env!("CARGO_PKG_NAME"), "/", env!("CARGO_PKG_VERSION")).to_owned(),
+            ),
             client: reqwest::Client::new(),
             basic_auth: None,
             oauth_access_token: None,
@@ -0,0 +1,61 @@
+/*
+ * Binance Public Spot API
+ *
+ * OpenAPI Specifications for the Binance Public Spot API  API documents:   - [https://github.com/binance/binance-spot-api-docs](https://github.com/binance/binance-spot-api-docs)   - [https://binance-docs.github.io/apidocs/spot/en](https://binance-docs.github.io/apidocs/spot/en)
+ *
+ * The version of the OpenAPI document: 1.0
+ *
+ * Generated by: https://openapi-generator.tech
+ */
+use super::{configuration, Error};
+use crate::{apis::ResponseContent, models};
+use reqwest;
+use serde::{Deserialize, Serialize};
+
+/// struct for typed errors of method [`api_v3_ticker_price_get`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ApiV3TickerPriceGetError {
+    Status400(models::Error),
+    UnknownValue(serde_json::Value),
+}
+
+/// Symbol Price Ticker  Latest price for a symbol.  Weight(IP): 2
+pub async fn api_v3_ticker_price_get(
+    configuration: &configuration::Configuration,
+    symbol: &str,
+) -> Result<models::ApiV3TickerPriceGet200Response, Error<ApiV3TickerPriceGetError>> {
+    let local_var_configuration = configuration;
+
+    let local_var_client = &local_var_configuration.client;
+
+    let local_var_uri_str = format!("{}/api/v3/ticker/price", local_var_configuration.base_path);
+    let mut local_var_req_builder =
+        local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
+
+    local_var_req_builder = local_var_req_builder.query(&[("symbol", &symbol.to_string())]);
+    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
+        local_var_req_builder =
+            local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
+    }
+
+    let local_var_req = local_var_req_builder.build()?;
+
+    let local_var_resp = local_var_client.execute(local_var_req).await?;
+
+    let local_var_status = local_var_resp.status();
+    let local_var_content = local_var_resp.text().await?;
+
+    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
+        serde_json::from_str(&local_var_content).map_err(Error::from)
+    } else {
+        let local_var_entity: Option<ApiV3TickerPriceGetError> =
+            serde_json::from_str(&local_var_content).ok();
+        let local_var_error = ResponseContent {
+            status: local_var_status,
+            content: local_var_content,
+            entity: local_var_entity,
+        };
+        Err(Error::ResponseError(local_var_error))
+    }
+}
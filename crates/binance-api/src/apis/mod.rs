@@ -1,3 +1,5 @@
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
 use std::error;
 use std::fmt;
 
@@ -92,6 +94,24 @@ pub fn parse_deep_object(prefix: &str, value: &serde_json::Value) -> Vec<(String
     unimplemented!("Only objects are supported with style=deepObject")
 }
 
+/// Signs the query string of a `USER_DATA` request with the secret key.
+pub(crate) fn calculate_signature(message: &str, secret_key: &str) -> String {
+    // Create an HMAC-SHA256 instance
+    let mut mac = Hmac::<Sha256>::new_from_slice(secret_key.as_bytes())
+        .expect("HMAC can take a key of any size");
+
+    // Process the message
+    mac.update(message.as_bytes());
+
+    // Get the resulting HMAC as bytes
+    let result = mac.finalize();
+
+    format!("{:x}", result.into_bytes())
+}
+
+pub mod market_api;
+pub mod simple_earn_api;
+pub mod trade_api;
 pub mod wallet_api;
 
 pub mod configuration;
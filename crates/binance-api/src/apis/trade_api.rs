@@ -0,0 +1,98 @@
+/*
+ * Binance Public Spot API
+ *
+ * OpenAPI Specifications for the Binance Public Spot API  API documents:   - [https://github.com/binance/binance-spot-api-docs](https://github.com/binance/binance-spot-api-docs)   - [https://binance-docs.github.io/apidocs/spot/en](https://binance-docs.github.io/apidocs/spot/en)
+ *
+ * The version of the OpenAPI document: 1.0
+ *
+ * Generated by: https://openapi-generator.tech
+ */
+use super::{calculate_signature, configuration, Error};
+use crate::{apis::ResponseContent, models};
+use chrono::Utc;
+use reqwest;
+use serde::{Deserialize, Serialize};
+
+/// struct for typed errors of method [`api_v3_account_get`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ApiV3AccountGetError {
+    Status400(models::Error),
+    Status401(models::Error),
+    UnknownValue(serde_json::Value),
+}
+
+/// Account Information  Get current account information.  Weight(IP): 20
+pub async fn api_v3_account_get(
+    configuration: &configuration::Configuration,
+    secret_key: &str,
+    omit_zero_balances: Option<bool>,
+    recv_window: Option<i64>,
+) -> Result<models::ApiV3AccountGet200Response, Error<ApiV3AccountGetError>> {
+    let local_var_configuration = configuration;
+
+    let local_var_client = &local_var_configuration.client;
+
+    let local_var_uri_str = format!("{}/api/v3/account", local_var_configuration.base_path);
+    let mut local_var_req_builder =
+        local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
+
+    if let Some(ref local_var_str) = omit_zero_balances {
+        local_var_req_builder =
+            local_var_req_builder.query(&[("omitZeroBalances", &local_var_str.to_string())]);
+    }
+    if let Some(ref local_var_str) = recv_window {
+        local_var_req_builder =
+            local_var_req_builder.query(&[("recvWindow", &local_var_str.to_string())]);
+    }
+    local_var_req_builder =
+        local_var_req_builder.query(&[("timestamp", &Utc::now().timestamp_millis())]);
+
+    let signature = {
+        calculate_signature(
+            local_var_req_builder
+                .try_clone()
+                .unwrap()
+                .build()?
+                .url()
+                .query()
+                .unwrap(),
+            secret_key,
+        )
+    };
+
+    local_var_req_builder = local_var_req_builder.query(&[("signature", &signature)]);
+
+    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
+        local_var_req_builder =
+            local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
+    }
+    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
+        let local_var_key = local_var_apikey.key.clone();
+        let local_var_value = match local_var_apikey.prefix {
+            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
+            None => local_var_key,
+        };
+        local_var_req_builder = local_var_req_builder.header("X-MBX-APIKEY", local_var_value);
+    };
+
+    let local_var_req = local_var_req_builder.build()?;
+
+    let local_var_resp = local_var_client.execute(local_var_req).await?;
+
+    let local_var_status = local_var_resp.status();
+    let local_var_content = local_var_resp.text().await?;
+
+    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
+        serde_json::from_str(&local_var_content).map_err(Error::from)
+    } else {
+        let local_var_entity: Option<ApiV3AccountGetError> =
+            serde_json::from_str(&local_var_content).ok();
+        let local_var_error = ResponseContent {
+            status: local_var_status,
+            content: local_var_content,
+            entity: local_var_entity,
+        };
+        Err(Error::ResponseError(local_var_error))
+    }
+}
@@ -0,0 +1,33 @@
+/*
+ * Binance Public Spot API
+ *
+ * OpenAPI Specifications for the Binance Public Spot API  API documents:   - [https://github.com/binance/binance-spot-api-docs](https://github.com/binance/binance-spot-api-docs)   - [https://binance-docs.github.io/apidocs/spot/en](https://binance-docs.github.io/apidocs/spot/en)
+ *
+ * The version of the OpenAPI document: 1.0
+ * 
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ApiV3AccountGet200Response {
+    #[serde(rename = "accountType", skip_serializing_if = "Option::is_none")]
+    pub account_type: Option<String>,
+    #[serde(rename = "canTrade", skip_serializing_if = "Option::is_none")]
+    pub can_trade: Option<bool>,
+    #[serde(rename = "balances")]
+    pub balances: Vec<models::ApiV3AccountGet200ResponseBalancesInner>,
+}
+
+impl ApiV3AccountGet200Response {
+    pub fn new(balances: Vec<models::ApiV3AccountGet200ResponseBalancesInner>) -> ApiV3AccountGet200Response {
+        ApiV3AccountGet200Response {
+            account_type: None,
+            can_trade: None,
+            balances,
+        }
+    }
+}
+
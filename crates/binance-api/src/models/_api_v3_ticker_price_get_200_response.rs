@@ -0,0 +1,30 @@
+/*
+ * Binance Public Spot API
+ *
+ * OpenAPI Specifications for the Binance Public Spot API  API documents:   - [https://github.com/binance/binance-spot-api-docs](https://github.com/binance/binance-spot-api-docs)   - [https://binance-docs.github.io/apidocs/spot/en](https://binance-docs.github.io/apidocs/spot/en)
+ *
+ * The version of the OpenAPI document: 1.0
+ * 
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ApiV3TickerPriceGet200Response {
+    #[serde(rename = "symbol")]
+    pub symbol: String,
+    #[serde(rename = "price")]
+    pub price: String,
+}
+
+impl ApiV3TickerPriceGet200Response {
+    pub fn new(symbol: String, price: String) -> ApiV3TickerPriceGet200Response {
+        ApiV3TickerPriceGet200Response {
+            symbol,
+            price,
+        }
+    }
+}
+
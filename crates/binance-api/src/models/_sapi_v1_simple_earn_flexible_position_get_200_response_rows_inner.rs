@@ -0,0 +1,36 @@
+/*
+ * Binance Public Spot API
+ *
+ * OpenAPI Specifications for the Binance Public Spot API  API documents:   - [https://github.com/binance/binance-spot-api-docs](https://github.com/binance/binance-spot-api-docs)   - [https://binance-docs.github.io/apidocs/spot/en](https://binance-docs.github.io/apidocs/spot/en)
+ *
+ * The version of the OpenAPI document: 1.0
+ * 
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct SapiV1SimpleEarnFlexiblePositionGet200ResponseRowsInner {
+    #[serde(rename = "totalAmount")]
+    pub total_amount: String,
+    #[serde(rename = "asset")]
+    pub asset: String,
+    #[serde(rename = "productId", skip_serializing_if = "Option::is_none")]
+    pub product_id: Option<String>,
+    #[serde(rename = "canRedeem", skip_serializing_if = "Option::is_none")]
+    pub can_redeem: Option<bool>,
+}
+
+impl SapiV1SimpleEarnFlexiblePositionGet200ResponseRowsInner {
+    pub fn new(total_amount: String, asset: String) -> SapiV1SimpleEarnFlexiblePositionGet200ResponseRowsInner {
+        SapiV1SimpleEarnFlexiblePositionGet200ResponseRowsInner {
+            total_amount,
+            asset,
+            product_id: None,
+            can_redeem: None,
+        }
+    }
+}
+
@@ -0,0 +1,39 @@
+/*
+ * Binance Public Spot API
+ *
+ * OpenAPI Specifications for the Binance Public Spot API  API documents:   - [https://github.com/binance/binance-spot-api-docs](https://github.com/binance/binance-spot-api-docs)   - [https://binance-docs.github.io/apidocs/spot/en](https://binance-docs.github.io/apidocs/spot/en)
+ *
+ * The version of the OpenAPI document: 1.0
+ * 
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct SapiV1SimpleEarnLockedPositionGet200ResponseRowsInner {
+    #[serde(rename = "positionId", skip_serializing_if = "Option::is_none")]
+    pub position_id: Option<i64>,
+    #[serde(rename = "projectId", skip_serializing_if = "Option::is_none")]
+    pub project_id: Option<String>,
+    #[serde(rename = "asset")]
+    pub asset: String,
+    #[serde(rename = "amount")]
+    pub amount: String,
+    #[serde(rename = "status", skip_serializing_if = "Option::is_none")]
+    pub status: Option<String>,
+}
+
+impl SapiV1SimpleEarnLockedPositionGet200ResponseRowsInner {
+    pub fn new(asset: String, amount: String) -> SapiV1SimpleEarnLockedPositionGet200ResponseRowsInner {
+        SapiV1SimpleEarnLockedPositionGet200ResponseRowsInner {
+            position_id: None,
+            project_id: None,
+            asset,
+            amount,
+            status: None,
+        }
+    }
+}
+
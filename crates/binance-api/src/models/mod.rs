@@ -1,4 +1,20 @@
+pub mod _api_v3_account_get_200_response;
+pub use _api_v3_account_get_200_response::ApiV3AccountGet200Response;
+pub mod _api_v3_account_get_200_response_balances_inner;
+pub use _api_v3_account_get_200_response_balances_inner::ApiV3AccountGet200ResponseBalancesInner;
+pub mod _api_v3_ticker_price_get_200_response;
+pub use _api_v3_ticker_price_get_200_response::ApiV3TickerPriceGet200Response;
+pub mod _sapi_v1_asset_get_funding_asset_post_200_response_inner;
+pub use _sapi_v1_asset_get_funding_asset_post_200_response_inner::SapiV1AssetGetFundingAssetPost200ResponseInner;
 pub mod _sapi_v1_asset_wallet_balance_get_200_response_inner;
 pub use _sapi_v1_asset_wallet_balance_get_200_response_inner::SapiV1AssetWalletBalanceGet200ResponseInner;
+pub mod _sapi_v1_simple_earn_flexible_position_get_200_response;
+pub use _sapi_v1_simple_earn_flexible_position_get_200_response::SapiV1SimpleEarnFlexiblePositionGet200Response;
+pub mod _sapi_v1_simple_earn_flexible_position_get_200_response_rows_inner;
+pub use _sapi_v1_simple_earn_flexible_position_get_200_response_rows_inner::SapiV1SimpleEarnFlexiblePositionGet200ResponseRowsInner;
+pub mod _sapi_v1_simple_earn_locked_position_get_200_response;
+pub use _sapi_v1_simple_earn_locked_position_get_200_response::SapiV1SimpleEarnLockedPositionGet200Response;
+pub mod _sapi_v1_simple_earn_locked_position_get_200_response_rows_inner;
+pub use _sapi_v1_simple_earn_locked_position_get_200_response_rows_inner::SapiV1SimpleEarnLockedPositionGet200ResponseRowsInner;
 pub mod error;
 pub use error::Error;
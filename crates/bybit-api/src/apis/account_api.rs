@@ -0,0 +1,41 @@
+/*
+ * Bybit V5 API
+ *
+ * Client for the Bybit V5 API  API documents:   - [https://bybit-exchange.github.io/docs/v5/intro](https://bybit-exchange.github.io/docs/v5/intro)
+ *
+ * The version of the OpenAPI document: 5.0
+ */
+use super::{configuration, execute, signed_get, Error};
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+/// struct for typed errors of method [`get_wallet_balance`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum GetWalletBalanceError {
+    Status200(models::Error),
+    UnknownValue(serde_json::Value),
+}
+
+/// Obtain wallet balance and asset information of each currency. `account_type`
+/// is `UNIFIED` for unified trading accounts.
+pub async fn get_wallet_balance(
+    configuration: &configuration::Configuration,
+    api_secret: &str,
+    account_type: &str,
+    coin: Option<&str>,
+) -> Result<models::WalletBalanceResponse, Error<GetWalletBalanceError>> {
+    let mut local_var_query = vec![("accountType", account_type.to_string())];
+    if let Some(local_var_str) = coin {
+        local_var_query.push(("coin", local_var_str.to_string()));
+    }
+
+    let local_var_req = signed_get(
+        configuration,
+        api_secret,
+        "/v5/account/wallet-balance",
+        &local_var_query,
+    )?;
+
+    execute(configuration, local_var_req).await
+}
@@ -0,0 +1,41 @@
+/*
+ * Bybit V5 API
+ *
+ * Client for the Bybit V5 API  API documents:   - [https://bybit-exchange.github.io/docs/v5/intro](https://bybit-exchange.github.io/docs/v5/intro)
+ *
+ * The version of the OpenAPI document: 5.0
+ */
+use super::{configuration, execute, signed_get, Error};
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+/// struct for typed errors of method [`get_all_coins_balance`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum GetAllCoinsBalanceError {
+    Status200(models::Error),
+    UnknownValue(serde_json::Value),
+}
+
+/// Query the balance of every coin in an account, e.g. the `FUND` (funding)
+/// account.
+pub async fn get_all_coins_balance(
+    configuration: &configuration::Configuration,
+    api_secret: &str,
+    account_type: &str,
+    coin: Option<&str>,
+) -> Result<models::AllCoinsBalanceResponse, Error<GetAllCoinsBalanceError>> {
+    let mut local_var_query = vec![("accountType", account_type.to_string())];
+    if let Some(local_var_str) = coin {
+        local_var_query.push(("coin", local_var_str.to_string()));
+    }
+
+    let local_var_req = signed_get(
+        configuration,
+        api_secret,
+        "/v5/asset/transfer/query-account-coins-balance",
+        &local_var_query,
+    )?;
+
+    execute(configuration, local_var_req).await
+}
@@ -0,0 +1,42 @@
+/*
+ * Bybit V5 API
+ *
+ * Client for the Bybit V5 API  API documents:   - [https://bybit-exchange.github.io/docs/v5/intro](https://bybit-exchange.github.io/docs/v5/intro)
+ *
+ * The version of the OpenAPI document: 5.0
+ */
+
+#[derive(Debug, Clone)]
+pub struct Configuration {
+    pub base_path: String,
+    pub user_agent: Option<String>,
+    pub client: reqwest::Client,
+    pub api_key: Option<ApiKey>,
+    /// Milliseconds a signed request stays valid after its timestamp.
+    pub recv_window: i64,
+}
+
+#[derive(Debug, Clone)]
+pub struct ApiKey {
+    pub key: String,
+}
+
+impl Configuration {
+    pub fn new() -> Configuration {
+        Configuration::default()
+    }
+}
+
+impl Default for Configuration {
+    fn default() -> Self {
+        Configuration {
+            base_path: "https://api.bybit.com".to_owned(),
+            user_agent: Some(
+                concat!(env!("CARGO_PKG_NAME"), "/", env!("CARGO_PKG_VERSION")).to_owned(),
+            ),
+            client: reqwest::Client::new(),
+            api_key: None,
+            recv_window: 5000,
+        }
+    }
+}
@@ -0,0 +1,40 @@
+/*
+ * Bybit V5 API
+ *
+ * Client for the Bybit V5 API  API documents:   - [https://bybit-exchange.github.io/docs/v5/intro](https://bybit-exchange.github.io/docs/v5/intro)
+ *
+ * The version of the OpenAPI document: 5.0
+ */
+use super::{configuration, execute, signed_get, Error};
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+/// struct for typed errors of method [`get_earn_position`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum GetEarnPositionError {
+    Status200(models::Error),
+    UnknownValue(serde_json::Value),
+}
+
+/// Get staked positions. `category` is `FlexibleSaving` or `OnChain`.
+pub async fn get_earn_position(
+    configuration: &configuration::Configuration,
+    api_secret: &str,
+    category: &str,
+    coin: Option<&str>,
+) -> Result<models::EarnPositionResponse, Error<GetEarnPositionError>> {
+    let mut local_var_query = vec![("category", category.to_string())];
+    if let Some(local_var_str) = coin {
+        local_var_query.push(("coin", local_var_str.to_string()));
+    }
+
+    let local_var_req = signed_get(
+        configuration,
+        api_secret,
+        "/v5/earn/position",
+        &local_var_query,
+    )?;
+
+    execute(configuration, local_var_req).await
+}
@@ -0,0 +1,46 @@
+/*
+ * Bybit V5 API
+ *
+ * Client for the Bybit V5 API  API documents:   - [https://bybit-exchange.github.io/docs/v5/intro](https://bybit-exchange.github.io/docs/v5/intro)
+ *
+ * The version of the OpenAPI document: 5.0
+ */
+use super::{configuration, execute, Error};
+use crate::models;
+use reqwest;
+use serde::{Deserialize, Serialize};
+
+/// struct for typed errors of method [`get_tickers`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum GetTickersError {
+    Status200(models::Error),
+    UnknownValue(serde_json::Value),
+}
+
+/// Query the latest price snapshot of a symbol, e.g. `BTCUSDT` in the `spot`
+/// category. Does not require authentication.
+pub async fn get_tickers(
+    configuration: &configuration::Configuration,
+    category: &str,
+    symbol: &str,
+) -> Result<models::TickersResponse, Error<GetTickersError>> {
+    let local_var_configuration = configuration;
+
+    let local_var_client = &local_var_configuration.client;
+
+    let local_var_uri_str = format!("{}/v5/market/tickers", local_var_configuration.base_path);
+    let mut local_var_req_builder =
+        local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
+
+    local_var_req_builder = local_var_req_builder.query(&[("category", &category.to_string())]);
+    local_var_req_builder = local_var_req_builder.query(&[("symbol", &symbol.to_string())]);
+    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
+        local_var_req_builder =
+            local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
+    }
+
+    let local_var_req = local_var_req_builder.build()?;
+
+    execute(local_var_configuration, local_var_req).await
+}
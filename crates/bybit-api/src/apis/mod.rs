@@ -0,0 +1,231 @@
+use crate::models;
+use chrono::Utc;
+use hmac::{Hmac, Mac};
+use serde::de::DeserializeOwned;
+use sha2::Sha256;
+use std::error;
+use std::fmt;
+
+#[derive(Debug, Clone)]
+pub struct ResponseContent<T> {
+    pub status: reqwest::StatusCode,
+    pub content: String,
+    pub entity: Option<T>,
+}
+
+#[derive(Debug)]
+pub enum Error<T> {
+    Reqwest(reqwest::Error),
+    Serde(serde_json::Error),
+    Io(std::io::Error),
+    ResponseError(ResponseContent<T>),
+    /// The request could not be signed, e.g. because the API key is invalid.
+    Signing(String),
+}
+
+impl<T> fmt::Display for Error<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (module, e) = match self {
+            Error::Reqwest(e) => ("reqwest", e.to_string()),
+            Error::Serde(e) => ("serde", e.to_string()),
+            Error::Io(e) => ("IO", e.to_string()),
+            Error::ResponseError(e) => ("response", format!("status code {}", e.status)),
+            Error::Signing(e) => ("signing", e.clone()),
+        };
+        write!(f, "error in {}: {}", module, e)
+    }
+}
+
+impl<T: fmt::Debug> error::Error for Error<T> {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        Some(match self {
+            Error::Reqwest(e) => e,
+            Error::Serde(e) => e,
+            Error::Io(e) => e,
+            Error::ResponseError(_) | Error::Signing(_) => return None,
+        })
+    }
+}
+
+impl<T> From<reqwest::Error> for Error<T> {
+    fn from(e: reqwest::Error) -> Self {
+        Error::Reqwest(e)
+    }
+}
+
+impl<T> From<serde_json::Error> for Error<T> {
+    fn from(e: serde_json::Error) -> Self {
+        Error::Serde(e)
+    }
+}
+
+impl<T> From<std::io::Error> for Error<T> {
+    fn from(e: std::io::Error) -> Self {
+        Error::Io(e)
+    }
+}
+
+/// Signs a private request:
+/// `hex(HMAC-SHA256(secret, timestamp + key + recv_window + query))`.
+pub fn calculate_signature(
+    timestamp: i64,
+    api_key: &str,
+    recv_window: i64,
+    query: &str,
+    api_secret: &str,
+) -> String {
+    let mut mac = Hmac::<Sha256>::new_from_slice(api_secret.as_bytes())
+        .expect("HMAC can take a key of any size");
+
+    mac.update(format!("{timestamp}{api_key}{recv_window}{query}").as_bytes());
+
+    format!("{:x}", mac.finalize().into_bytes())
+}
+
+/// Sends a request and parses its response. Bybit reports most errors with a
+/// 200 status, so a response with a non-zero `retCode` is returned as a
+/// `ResponseError` too.
+pub(crate) async fn execute<R, E>(
+    configuration: &configuration::Configuration,
+    request: reqwest::Request,
+) -> Result<R, Error<E>>
+where
+    R: DeserializeOwned,
+    E: DeserializeOwned,
+{
+    let local_var_resp = configuration.client.execute(request).await?;
+
+    let local_var_status = local_var_resp.status();
+    let local_var_content = local_var_resp.text().await?;
+
+    let local_var_failed = local_var_status.is_client_error()
+        || local_var_status.is_server_error()
+        || serde_json::from_str::<models::Error>(&local_var_content).is_ok_and(|e| e.ret_code != 0);
+
+    if !local_var_failed {
+        serde_json::from_str(&local_var_content).map_err(Error::from)
+    } else {
+        let local_var_entity: Option<E> = serde_json::from_str(&local_var_content).ok();
+        let local_var_error = ResponseContent {
+            status: local_var_status,
+            content: local_var_content,
+            entity: local_var_entity,
+        };
+        Err(Error::ResponseError(local_var_error))
+    }
+}
+
+/// Builds a signed `GET` request to a private endpoint.
+pub(crate) fn signed_get<E>(
+    configuration: &configuration::Configuration,
+    api_secret: &str,
+    path: &str,
+    query: &[(&str, String)],
+) -> Result<reqwest::Request, Error<E>> {
+    let local_var_uri_str = format!("{}{}", configuration.base_path, path);
+    let mut local_var_req_builder = configuration
+        .client
+        .request(reqwest::Method::GET, local_var_uri_str.as_str())
+        .query(query);
+
+    if let Some(ref local_var_user_agent) = configuration.user_agent {
+        local_var_req_builder =
+            local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
+    }
+
+    let local_var_api_key = configuration
+        .api_key
+        .as_ref()
+        .map(|k| k.key.clone())
+        .unwrap_or_default();
+    let local_var_timestamp = Utc::now().timestamp_millis();
+
+    let local_var_req = local_var_req_builder.build()?;
+    let local_var_signature = calculate_signature(
+        local_var_timestamp,
+        &local_var_api_key,
+        configuration.recv_window,
+        local_var_req.url().query().unwrap_or_default(),
+        api_secret,
+    );
+
+    let mut local_var_req = local_var_req;
+    let local_var_headers = local_var_req.headers_mut();
+    for (local_var_name, local_var_value) in [
+        ("X-BAPI-API-KEY", local_var_api_key),
+        ("X-BAPI-TIMESTAMP", local_var_timestamp.to_string()),
+        ("X-BAPI-RECV-WINDOW", configuration.recv_window.to_string()),
+        ("X-BAPI-SIGN", local_var_signature),
+    ] {
+        local_var_headers.insert(
+            local_var_name,
+            local_var_value
+                .parse()
+                .map_err(|_| Error::Signing(format!("invalid {local_var_name} header")))?,
+        );
+    }
+
+    Ok(local_var_req)
+}
+
+pub mod account_api;
+pub mod asset_api;
+pub mod earn_api;
+pub mod market_api;
+
+pub mod configuration;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn signs_timestamp_key_recv_window_and_query() {
+        // hex(HMAC-SHA256("secret", "1658384314791key5000accountType=UNIFIED&coin=BTC"))
+        assert_eq!(
+            calculate_signature(
+                1658384314791,
+                "key",
+                5000,
+                "accountType=UNIFIED&coin=BTC",
+                "secret"
+            ),
+            "8ab81c8c912ced4eb81c6b25ad686f998f8c43b8893fe74703f23cf330fe0f86"
+        );
+    }
+
+    #[test]
+    fn signs_the_query_of_a_private_request() {
+        let configuration = configuration::Configuration {
+            api_key: Some(configuration::ApiKey {
+                key: "key".to_string(),
+            }),
+            ..Default::default()
+        };
+
+        let request = signed_get::<()>(
+            &configuration,
+            "secret",
+            "/v5/account/wallet-balance",
+            &[("accountType", "UNIFIED".to_string())],
+        )
+        .unwrap();
+
+        let header = |name: &str| request.headers()[name].to_str().unwrap().to_string();
+        assert_eq!(header("X-BAPI-API-KEY"), "key");
+        assert_eq!(
+            header("X-BAPI-RECV-WINDOW"),
+            configuration.recv_window.to_string()
+        );
+        assert_eq!(
+            header("X-BAPI-SIGN"),
+            calculate_signature(
+                header("X-BAPI-TIMESTAMP").parse().unwrap(),
+                "key",
+                configuration.recv_window,
+                "accountType=UNIFIED",
+                "secret"
+            )
+        );
+    }
+}
@@ -0,0 +1,27 @@
+/*
+ * Bybit V5 API
+ *
+ * Client for the Bybit V5 API  API documents:   - [https://bybit-exchange.github.io/docs/v5/intro](https://bybit-exchange.github.io/docs/v5/intro)
+ *
+ * The version of the OpenAPI document: 5.0
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct AllCoinsBalance {
+    #[serde(rename = "accountType")]
+    pub account_type: String,
+    #[serde(rename = "balance", default)]
+    pub balance: Vec<models::FundBalance>,
+}
+
+impl AllCoinsBalance {
+    pub fn new(account_type: String, balance: Vec<models::FundBalance>) -> AllCoinsBalance {
+        AllCoinsBalance {
+            account_type,
+            balance,
+        }
+    }
+}
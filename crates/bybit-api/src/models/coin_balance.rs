@@ -0,0 +1,35 @@
+/*
+ * Bybit V5 API
+ *
+ * Client for the Bybit V5 API  API documents:   - [https://bybit-exchange.github.io/docs/v5/intro](https://bybit-exchange.github.io/docs/v5/intro)
+ *
+ * The version of the OpenAPI document: 5.0
+ */
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct CoinBalance {
+    #[serde(rename = "coin")]
+    pub coin: String,
+    /// Balance of the coin, including locked amounts
+    #[serde(rename = "walletBalance")]
+    pub wallet_balance: String,
+    /// Amount locked in spot open orders
+    #[serde(rename = "locked", skip_serializing_if = "Option::is_none")]
+    pub locked: Option<String>,
+    /// USD value of the coin
+    #[serde(rename = "usdValue", skip_serializing_if = "Option::is_none")]
+    pub usd_value: Option<String>,
+}
+
+impl CoinBalance {
+    pub fn new(coin: String, wallet_balance: String) -> CoinBalance {
+        CoinBalance {
+            coin,
+            wallet_balance,
+            locked: None,
+            usd_value: None,
+        }
+    }
+}
@@ -0,0 +1,33 @@
+/*
+ * Bybit V5 API
+ *
+ * Client for the Bybit V5 API  API documents:   - [https://bybit-exchange.github.io/docs/v5/intro](https://bybit-exchange.github.io/docs/v5/intro)
+ *
+ * The version of the OpenAPI document: 5.0
+ */
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct EarnPosition {
+    #[serde(rename = "coin")]
+    pub coin: String,
+    #[serde(rename = "productId", skip_serializing_if = "Option::is_none")]
+    pub product_id: Option<String>,
+    /// Staked amount
+    #[serde(rename = "amount")]
+    pub amount: String,
+    #[serde(rename = "totalPnl", skip_serializing_if = "Option::is_none")]
+    pub total_pnl: Option<String>,
+}
+
+impl EarnPosition {
+    pub fn new(coin: String, amount: String) -> EarnPosition {
+        EarnPosition {
+            coin,
+            product_id: None,
+            amount,
+            total_pnl: None,
+        }
+    }
+}
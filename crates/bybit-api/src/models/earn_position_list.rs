@@ -0,0 +1,22 @@
+/*
+ * Bybit V5 API
+ *
+ * Client for the Bybit V5 API  API documents:   - [https://bybit-exchange.github.io/docs/v5/intro](https://bybit-exchange.github.io/docs/v5/intro)
+ *
+ * The version of the OpenAPI document: 5.0
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct EarnPositionList {
+    #[serde(rename = "list", default)]
+    pub list: Vec<models::EarnPosition>,
+}
+
+impl EarnPositionList {
+    pub fn new(list: Vec<models::EarnPosition>) -> EarnPositionList {
+        EarnPositionList { list }
+    }
+}
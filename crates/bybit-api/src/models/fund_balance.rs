@@ -0,0 +1,29 @@
+/*
+ * Bybit V5 API
+ *
+ * Client for the Bybit V5 API  API documents:   - [https://bybit-exchange.github.io/docs/v5/intro](https://bybit-exchange.github.io/docs/v5/intro)
+ *
+ * The version of the OpenAPI document: 5.0
+ */
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct FundBalance {
+    #[serde(rename = "coin")]
+    pub coin: String,
+    #[serde(rename = "walletBalance")]
+    pub wallet_balance: String,
+    #[serde(rename = "transferBalance", skip_serializing_if = "Option::is_none")]
+    pub transfer_balance: Option<String>,
+}
+
+impl FundBalance {
+    pub fn new(coin: String, wallet_balance: String) -> FundBalance {
+        FundBalance {
+            coin,
+            wallet_balance,
+            transfer_balance: None,
+        }
+    }
+}
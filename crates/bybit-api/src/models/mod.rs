@@ -0,0 +1,28 @@
+pub mod all_coins_balance;
+pub use all_coins_balance::AllCoinsBalance;
+pub mod all_coins_balance_response;
+pub use all_coins_balance_response::AllCoinsBalanceResponse;
+pub mod coin_balance;
+pub use coin_balance::CoinBalance;
+pub mod earn_position;
+pub use earn_position::EarnPosition;
+pub mod earn_position_list;
+pub use earn_position_list::EarnPositionList;
+pub mod earn_position_response;
+pub use earn_position_response::EarnPositionResponse;
+pub mod error;
+pub use error::Error;
+pub mod fund_balance;
+pub use fund_balance::FundBalance;
+pub mod ticker;
+pub use ticker::Ticker;
+pub mod ticker_list;
+pub use ticker_list::TickerList;
+pub mod tickers_response;
+pub use tickers_response::TickersResponse;
+pub mod wallet_balance;
+pub use wallet_balance::WalletBalance;
+pub mod wallet_balance_list;
+pub use wallet_balance_list::WalletBalanceList;
+pub mod wallet_balance_response;
+pub use wallet_balance_response::WalletBalanceResponse;
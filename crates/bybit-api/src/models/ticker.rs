@@ -0,0 +1,23 @@
+/*
+ * Bybit V5 API
+ *
+ * Client for the Bybit V5 API  API documents:   - [https://bybit-exchange.github.io/docs/v5/intro](https://bybit-exchange.github.io/docs/v5/intro)
+ *
+ * The version of the OpenAPI document: 5.0
+ */
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Ticker {
+    #[serde(rename = "symbol")]
+    pub symbol: String,
+    #[serde(rename = "lastPrice")]
+    pub last_price: String,
+}
+
+impl Ticker {
+    pub fn new(symbol: String, last_price: String) -> Ticker {
+        Ticker { symbol, last_price }
+    }
+}
@@ -0,0 +1,27 @@
+/*
+ * Bybit V5 API
+ *
+ * Client for the Bybit V5 API  API documents:   - [https://bybit-exchange.github.io/docs/v5/intro](https://bybit-exchange.github.io/docs/v5/intro)
+ *
+ * The version of the OpenAPI document: 5.0
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct TickerList {
+    #[serde(rename = "category", skip_serializing_if = "Option::is_none")]
+    pub category: Option<String>,
+    #[serde(rename = "list", default)]
+    pub list: Vec<models::Ticker>,
+}
+
+impl TickerList {
+    pub fn new(list: Vec<models::Ticker>) -> TickerList {
+        TickerList {
+            category: None,
+            list,
+        }
+    }
+}
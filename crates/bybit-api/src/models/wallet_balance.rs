@@ -0,0 +1,31 @@
+/*
+ * Bybit V5 API
+ *
+ * Client for the Bybit V5 API  API documents:   - [https://bybit-exchange.github.io/docs/v5/intro](https://bybit-exchange.github.io/docs/v5/intro)
+ *
+ * The version of the OpenAPI document: 5.0
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct WalletBalance {
+    #[serde(rename = "accountType")]
+    pub account_type: String,
+    /// Account equity in USD
+    #[serde(rename = "totalEquity", skip_serializing_if = "Option::is_none")]
+    pub total_equity: Option<String>,
+    #[serde(rename = "coin", default)]
+    pub coin: Vec<models::CoinBalance>,
+}
+
+impl WalletBalance {
+    pub fn new(account_type: String, coin: Vec<models::CoinBalance>) -> WalletBalance {
+        WalletBalance {
+            account_type,
+            total_equity: None,
+            coin,
+        }
+    }
+}
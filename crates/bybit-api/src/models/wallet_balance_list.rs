@@ -0,0 +1,22 @@
+/*
+ * Bybit V5 API
+ *
+ * Client for the Bybit V5 API  API documents:   - [https://bybit-exchange.github.io/docs/v5/intro](https://bybit-exchange.github.io/docs/v5/intro)
+ *
+ * The version of the OpenAPI document: 5.0
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct WalletBalanceList {
+    #[serde(rename = "list", default)]
+    pub list: Vec<models::WalletBalance>,
+}
+
+impl WalletBalanceList {
+    pub fn new(list: Vec<models::WalletBalance>) -> WalletBalanceList {
+        WalletBalanceList { list }
+    }
+}
@@ -0,0 +1,31 @@
+/*
+ * Bybit V5 API
+ *
+ * Client for the Bybit V5 API  API documents:   - [https://bybit-exchange.github.io/docs/v5/intro](https://bybit-exchange.github.io/docs/v5/intro)
+ *
+ * The version of the OpenAPI document: 5.0
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct WalletBalanceResponse {
+    /// Zero on success
+    #[serde(rename = "retCode")]
+    pub ret_code: i64,
+    #[serde(rename = "retMsg")]
+    pub ret_msg: String,
+    #[serde(rename = "result", skip_serializing_if = "Option::is_none")]
+    pub result: Option<models::WalletBalanceList>,
+}
+
+impl WalletBalanceResponse {
+    pub fn new(ret_code: i64, ret_msg: String) -> WalletBalanceResponse {
+        WalletBalanceResponse {
+            ret_code,
+            ret_msg,
+            result: None,
+        }
+    }
+}
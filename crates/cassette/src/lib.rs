@@ -0,0 +1,321 @@
+//! Record and replay of HTTP responses for tests.
+//!
+//! A [`Cassette`] is a local server that answers requests with the responses
+//! saved in a JSON file, so code calling a third-party API can be tested
+//! offline by pointing its base URL at [`Cassette::url`]. With
+//! `RECORD_CASSETTES=true` the requests are forwarded to the real API instead
+//! and the file is rewritten with its responses when the cassette is dropped.
+//!
+//! Query parameters that change on every request, like the `timestamp` and
+//! `signature` of signed exchange requests, are left out when matching and
+//! saving requests.
+
+use std::collections::HashMap;
+use std::env;
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::{SocketAddr, TcpListener, TcpStream};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// Query parameters ignored when matching requests.
+const VOLATILE_PARAMS: [&str; 3] = ["timestamp", "signature", "recvWindow"];
+/// Request headers not forwarded when recording.
+const HOP_HEADERS: [&str; 4] = ["host", "connection", "content-length", "accept-encoding"];
+
+/// A saved request and its response.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Interaction {
+    pub method: String,
+    /// Path and query, without the volatile parameters.
+    pub path: String,
+    pub status: u16,
+    /// The response body: its JSON when it is JSON, its text otherwise, so
+    /// that malformed payloads can be saved too.
+    pub body: Value,
+}
+
+impl Interaction {
+    fn body_text(&self) -> String {
+        match &self.body {
+            Value::String(text) => text.clone(),
+            json => json.to_string(),
+        }
+    }
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct Tape {
+    interactions: Vec<Interaction>,
+}
+
+#[derive(Debug, Default)]
+struct State {
+    tape: Tape,
+    /// How many times each interaction was replayed.
+    plays: Vec<usize>,
+    requests: Vec<String>,
+}
+
+/// A server replaying, or recording, the interactions of one API.
+pub struct Cassette {
+    path: PathBuf,
+    address: SocketAddr,
+    recording: bool,
+    state: Arc<Mutex<State>>,
+    stopped: Arc<AtomicBool>,
+}
+
+impl Cassette {
+    /// Starts a server replaying the cassette at `path`, or recording the
+    /// responses of `upstream` to it when `RECORD_CASSETTES` is `true`.
+    ///
+    /// Panics when replaying a cassette that cannot be read.
+    pub fn start(path: impl AsRef<Path>, upstream: &str) -> Self {
+        let path = path.as_ref().to_path_buf();
+        let recording = env::var("RECORD_CASSETTES").is_ok_and(|v| v == "true");
+
+        let tape = if recording {
+            Tape::default()
+        } else {
+            let json = std::fs::read_to_string(&path)
+                .unwrap_or_else(|e| panic!("cannot read cassette {}: {e}", path.display()));
+            serde_json::from_str(&json)
+                .unwrap_or_else(|e| panic!("invalid cassette {}: {e}", path.display()))
+        };
+
+        let listener = TcpListener::bind("127.0.0.1:0").expect("bind the cassette server");
+        let address = listener.local_addr().expect("cassette server address");
+
+        let state = Arc::new(Mutex::new(State {
+            plays: vec![0; tape.interactions.len()],
+            tape,
+            requests: vec![],
+        }));
+        let stopped = Arc::new(AtomicBool::new(false));
+
+        let server_state = state.clone();
+        let server_stopped = stopped.clone();
+        let upstream = recording.then(|| upstream.trim_end_matches('/').to_string());
+        thread::spawn(move || {
+            let client = reqwest::blocking::Client::new();
+
+            for stream in listener.incoming() {
+                if server_stopped.load(Ordering::SeqCst) {
+                    break;
+                }
+
+                if let Ok(stream) = stream {
+                    // a client that hung up mid-request is not the test's concern
+                    let _ = serve(stream, &server_state, &client, upstream.as_deref());
+                }
+            }
+        });
+
+        Cassette {
+            path,
+            address,
+            recording,
+            state,
+            stopped,
+        }
+    }
+
+    /// The base URL to call instead of the API, e.g. `http://127.0.0.1:40123`.
+    pub fn url(&self) -> String {
+        format!("http://{}", self.address)
+    }
+
+    /// The requests received so far, as `METHOD /path?query`.
+    pub fn requests(&self) -> Vec<String> {
+        self.state().requests.clone()
+    }
+
+    /// The saved interactions that no request matched.
+    pub fn unplayed(&self) -> Vec<Interaction> {
+        let state = self.state();
+        state
+            .tape
+            .interactions
+            .iter()
+            .zip(&state.plays)
+            .filter(|(_, plays)| **plays == 0)
+            .map(|(interaction, _)| interaction.clone())
+            .collect()
+    }
+
+    fn state(&self) -> std::sync::MutexGuard<'_, State> {
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+impl Drop for Cassette {
+    fn drop(&mut self) {
+        self.stopped.store(true, Ordering::SeqCst);
+        // wake up the server thread blocked on accept
+        let _ = TcpStream::connect(self.address);
+
+        if self.recording {
+            let json =
+                serde_json::to_string_pretty(&self.state().tape).expect("cassette serializes");
+            if let Err(e) = std::fs::write(&self.path, json + "\n") {
+                eprintln!("Failed to save cassette {}: {e}", self.path.display());
+            }
+        }
+    }
+}
+
+/// Removes the volatile parameters from a request target.
+fn normalize(target: &str) -> String {
+    let Some((path, query)) = target.split_once('?') else {
+        return target.to_string();
+    };
+
+    let query = query
+        .split('&')
+        .filter(|pair| {
+            let key = pair.split_once('=').map_or(*pair, |(key, _)| key);
+            !VOLATILE_PARAMS.contains(&key)
+        })
+        .collect::<Vec<_>>();
+
+    if query.is_empty() {
+        path.to_string()
+    } else {
+        format!("{path}?{}", query.join("&"))
+    }
+}
+
+/// Replays the first saved response to a request that was not played yet,
+/// or the last one when all were, so repeated requests keep working.
+fn replay(state: &mut State, method: &str, path: &str) -> (u16, String) {
+    let matching = state
+        .tape
+        .interactions
+        .iter()
+        .enumerate()
+        .filter(|(_, i)| i.method == method && i.path == path)
+        .map(|(index, _)| index)
+        .collect::<Vec<_>>();
+
+    let Some(&index) = matching
+        .iter()
+        .find(|&&index| state.plays[index] == 0)
+        .or(matching.last())
+    else {
+        return (404, format!("No recorded response for {method} {path}"));
+    };
+
+    state.plays[index] += 1;
+    let interaction = &state.tape.interactions[index];
+    (interaction.status, interaction.body_text())
+}
+
+fn record(
+    client: &reqwest::blocking::Client,
+    upstream: &str,
+    method: &str,
+    target: &str,
+    headers: &HashMap<String, String>,
+    body: Vec<u8>,
+) -> (u16, String) {
+    let method = reqwest::Method::from_bytes(method.as_bytes()).unwrap_or(reqwest::Method::GET);
+    let mut request = client
+        .request(method, format!("{upstream}{target}"))
+        .body(body);
+
+    for (name, value) in headers {
+        if !HOP_HEADERS.contains(&name.as_str()) {
+            request = request.header(name, value);
+        }
+    }
+
+    match request.send() {
+        Ok(response) => {
+            let status = response.status().as_u16();
+            (status, response.text().unwrap_or_default())
+        }
+        Err(e) => (502, format!("Failed to reach {upstream}: {e}")),
+    }
+}
+
+/// Reads one request from a connection and answers it, closing the
+/// connection afterwards.
+fn serve(
+    stream: TcpStream,
+    state: &Mutex<State>,
+    client: &reqwest::blocking::Client,
+    upstream: Option<&str>,
+) -> std::io::Result<()> {
+    let mut reader = BufReader::new(stream.try_clone()?);
+
+    let mut request_line = String::new();
+    reader.read_line(&mut request_line)?;
+    let mut parts = request_line.split_whitespace();
+    let method = parts.next().unwrap_or_default().to_string();
+    let target = parts.next().unwrap_or_default().to_string();
+
+    let mut headers = HashMap::new();
+    loop {
+        let mut line = String::new();
+        if reader.read_line(&mut line)? == 0 || line.trim().is_empty() {
+            break;
+        }
+        if let Some((name, value)) = line.split_once(':') {
+            headers.insert(name.trim().to_lowercase(), value.trim().to_string());
+        }
+    }
+
+    let length = headers
+        .get("content-length")
+        .and_then(|l| l.parse().ok())
+        .unwrap_or(0);
+    let mut body = vec![0; length];
+    reader.read_exact(&mut body)?;
+
+    let path = normalize(&target);
+    state
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .requests
+        .push(format!("{method} {path}"));
+
+    let (status, body) = match upstream {
+        Some(upstream) => {
+            let (status, body) = record(client, upstream, &method, &target, &headers, body);
+
+            let mut state = state.lock().unwrap_or_else(|e| e.into_inner());
+            state.tape.interactions.push(Interaction {
+                method: method.clone(),
+                path,
+                status,
+                body: serde_json::from_str(&body).unwrap_or_else(|_| Value::String(body.clone())),
+            });
+            state.plays.push(1);
+
+            (status, body)
+        }
+        None => replay(
+            &mut state.lock().unwrap_or_else(|e| e.into_inner()),
+            &method,
+            &path,
+        ),
+    };
+
+    let reason = reqwest::StatusCode::from_u16(status)
+        .ok()
+        .and_then(|s| s.canonical_reason())
+        .unwrap_or_default();
+    let response = format!(
+        "HTTP/1.1 {status} {reason}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+        body.len()
+    );
+
+    let mut stream = stream;
+    stream.write_all(response.as_bytes())?;
+    stream.flush()
+}
@@ -0,0 +1,77 @@
+/*
+ * Coinbase Advanced Trade API
+ *
+ * Client for the Coinbase Advanced Trade API  API documents:   - [https://docs.cdp.coinbase.com/advanced-trade/docs/welcome](https://docs.cdp.coinbase.com/advanced-trade/docs/welcome)
+ *
+ * The version of the OpenAPI document: 3.0
+ */
+use super::{auth, configuration, Error};
+use crate::{apis::ResponseContent, models};
+use reqwest;
+use serde::{Deserialize, Serialize};
+
+/// struct for typed errors of method [`list_accounts`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ListAccountsError {
+    Status401(models::Error),
+    Status500(models::Error),
+    UnknownValue(serde_json::Value),
+}
+
+/// Get a list of authenticated accounts for the current user.
+pub async fn list_accounts(
+    configuration: &configuration::Configuration,
+    private_key: &str,
+    limit: Option<i32>,
+    cursor: Option<&str>,
+) -> Result<models::ListAccountsResponse, Error<ListAccountsError>> {
+    let local_var_configuration = configuration;
+
+    let local_var_client = &local_var_configuration.client;
+
+    let local_var_path = "/api/v3/brokerage/accounts";
+    let local_var_uri_str = format!("{}{}", local_var_configuration.base_path, local_var_path);
+    let mut local_var_req_builder =
+        local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
+
+    if let Some(ref local_var_str) = limit {
+        local_var_req_builder =
+            local_var_req_builder.query(&[("limit", &local_var_str.to_string())]);
+    }
+    if let Some(ref local_var_str) = cursor {
+        local_var_req_builder =
+            local_var_req_builder.query(&[("cursor", &local_var_str.to_string())]);
+    }
+    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
+        local_var_req_builder =
+            local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
+    }
+
+    let local_var_token = auth::build_jwt(
+        local_var_configuration,
+        private_key,
+        &reqwest::Method::GET,
+        local_var_path,
+    )?;
+    local_var_req_builder = local_var_req_builder.bearer_auth(local_var_token);
+
+    let local_var_req = local_var_req_builder.build()?;
+    let local_var_resp = local_var_client.execute(local_var_req).await?;
+
+    let local_var_status = local_var_resp.status();
+    let local_var_content = local_var_resp.text().await?;
+
+    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
+        serde_json::from_str(&local_var_content).map_err(Error::from)
+    } else {
+        let local_var_entity: Option<ListAccountsError> =
+            serde_json::from_str(&local_var_content).ok();
+        let local_var_error = ResponseContent {
+            status: local_var_status,
+            content: local_var_content,
+            entity: local_var_entity,
+        };
+        Err(Error::ResponseError(local_var_error))
+    }
+}
@@ -0,0 +1,40 @@
+/*
+ * Coinbase Advanced Trade API
+ *
+ * Client for the Coinbase Advanced Trade API  API documents:   - [https://docs.cdp.coinbase.com/advanced-trade/docs/welcome](https://docs.cdp.coinbase.com/advanced-trade/docs/welcome)
+ *
+ * The version of the OpenAPI document: 3.0
+ */
+
+#[derive(Debug, Clone)]
+pub struct Configuration {
+    pub base_path: String,
+    pub user_agent: Option<String>,
+    pub client: reqwest::Client,
+    /// CDP API key, e.g. `organizations/{org_id}/apiKeys/{key_id}`.
+    pub api_key: Option<ApiKey>,
+}
+
+#[derive(Debug, Clone)]
+pub struct ApiKey {
+    pub key: String,
+}
+
+impl Configuration {
+    pub fn new() -> Configuration {
+        Configuration::default()
+    }
+}
+
+impl Default for Configuration {
+    fn default() -> Self {
+        Configuration {
+            base_path: "https://api.coinbase.com".to_owned(),
+            user_agent: Some(
+                concat!(env!("CARGO_PKG_NAME"), "/", env!("CARGO_PKG_VERSION")).to_owned(),
+            ),
+            client: reqwest::Client::new(),
+            api_key: None,
+        }
+    }
+}
@@ -0,0 +1,62 @@
+/*
+ * Coinbase Advanced Trade API
+ *
+ * Client for the Coinbase Advanced Trade API  API documents:   - [https://docs.cdp.coinbase.com/advanced-trade/docs/welcome](https://docs.cdp.coinbase.com/advanced-trade/docs/welcome)
+ *
+ * The version of the OpenAPI document: 3.0
+ */
+use super::{configuration, Error};
+use crate::{apis::ResponseContent, models};
+use reqwest;
+use serde::{Deserialize, Serialize};
+
+/// struct for typed errors of method [`get_public_product`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum GetPublicProductError {
+    Status404(models::Error),
+    UnknownValue(serde_json::Value),
+}
+
+/// Get information on a single product by product ID, e.g. `BTC-USD`. Does not
+/// require authentication.
+pub async fn get_public_product(
+    configuration: &configuration::Configuration,
+    product_id: &str,
+) -> Result<models::Product, Error<GetPublicProductError>> {
+    let local_var_configuration = configuration;
+
+    let local_var_client = &local_var_configuration.client;
+
+    let local_var_uri_str = format!(
+        "{}/api/v3/brokerage/market/products/{product_id}",
+        local_var_configuration.base_path,
+        product_id = crate::apis::urlencode(product_id)
+    );
+    let mut local_var_req_builder =
+        local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
+
+    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
+        local_var_req_builder =
+            local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
+    }
+
+    let local_var_req = local_var_req_builder.build()?;
+    let local_var_resp = local_var_client.execute(local_var_req).await?;
+
+    let local_var_status = local_var_resp.status();
+    let local_var_content = local_var_resp.text().await?;
+
+    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
+        serde_json::from_str(&local_var_content).map_err(Error::from)
+    } else {
+        let local_var_entity: Option<GetPublicProductError> =
+            serde_json::from_str(&local_var_content).ok();
+        let local_var_error = ResponseContent {
+            status: local_var_status,
+            content: local_var_content,
+            entity: local_var_entity,
+        };
+        Err(Error::ResponseError(local_var_error))
+    }
+}
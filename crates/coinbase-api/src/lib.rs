@@ -0,0 +1,9 @@
+#![allow(clippy::too_many_arguments)]
+
+extern crate reqwest;
+extern crate serde;
+extern crate serde_json;
+extern crate url;
+
+pub mod apis;
+pub mod models;
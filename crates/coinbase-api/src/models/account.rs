@@ -0,0 +1,61 @@
+/*
+ * Coinbase Advanced Trade API
+ *
+ * Client for the Coinbase Advanced Trade API  API documents:   - [https://docs.cdp.coinbase.com/advanced-trade/docs/welcome](https://docs.cdp.coinbase.com/advanced-trade/docs/welcome)
+ *
+ * The version of the OpenAPI document: 3.0
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Account {
+    /// Unique identifier for account
+    #[serde(rename = "uuid")]
+    pub uuid: String,
+    /// Name for the account
+    #[serde(rename = "name")]
+    pub name: String,
+    /// Currency symbol for the account
+    #[serde(rename = "currency")]
+    pub currency: String,
+    #[serde(rename = "available_balance")]
+    pub available_balance: Box<models::Amount>,
+    /// Whether or not this account is the user's primary account
+    #[serde(rename = "default", skip_serializing_if = "Option::is_none")]
+    pub default: Option<bool>,
+    /// Whether or not this account is active and okay to use
+    #[serde(rename = "active", skip_serializing_if = "Option::is_none")]
+    pub active: Option<bool>,
+    /// Type of account, e.g. `ACCOUNT_TYPE_CRYPTO` or `ACCOUNT_TYPE_FIAT`
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    pub r#type: Option<String>,
+    /// Whether or not this account is ready to trade
+    #[serde(rename = "ready", skip_serializing_if = "Option::is_none")]
+    pub ready: Option<bool>,
+    /// Amount that is currently held in obligation
+    #[serde(rename = "hold", skip_serializing_if = "Option::is_none")]
+    pub hold: Option<Box<models::Amount>>,
+}
+
+impl Account {
+    pub fn new(
+        uuid: String,
+        name: String,
+        currency: String,
+        available_balance: models::Amount,
+    ) -> Account {
+        Account {
+            uuid,
+            name,
+            currency,
+            available_balance: Box::new(available_balance),
+            default: None,
+            active: None,
+            r#type: None,
+            ready: None,
+            hold: None,
+        }
+    }
+}
@@ -0,0 +1,25 @@
+/*
+ * Coinbase Advanced Trade API
+ *
+ * Client for the Coinbase Advanced Trade API  API documents:   - [https://docs.cdp.coinbase.com/advanced-trade/docs/welcome](https://docs.cdp.coinbase.com/advanced-trade/docs/welcome)
+ *
+ * The version of the OpenAPI document: 3.0
+ */
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Amount {
+    /// Amount of currency that this object represents
+    #[serde(rename = "value")]
+    pub value: String,
+    /// Denomination of the currency
+    #[serde(rename = "currency")]
+    pub currency: String,
+}
+
+impl Amount {
+    pub fn new(value: String, currency: String) -> Amount {
+        Amount { value, currency }
+    }
+}
@@ -0,0 +1,31 @@
+/*
+ * Coinbase Advanced Trade API
+ *
+ * Client for the Coinbase Advanced Trade API  API documents:   - [https://docs.cdp.coinbase.com/advanced-trade/docs/welcome](https://docs.cdp.coinbase.com/advanced-trade/docs/welcome)
+ *
+ * The version of the OpenAPI document: 3.0
+ */
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Error {
+    /// Error code
+    #[serde(rename = "error")]
+    pub error: String,
+    /// Error message
+    #[serde(rename = "message", skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+    #[serde(rename = "error_details", skip_serializing_if = "Option::is_none")]
+    pub error_details: Option<String>,
+}
+
+impl Error {
+    pub fn new(error: String) -> Error {
+        Error {
+            error,
+            message: None,
+            error_details: None,
+        }
+    }
+}
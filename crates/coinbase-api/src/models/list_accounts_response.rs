@@ -0,0 +1,36 @@
+/*
+ * Coinbase Advanced Trade API
+ *
+ * Client for the Coinbase Advanced Trade API  API documents:   - [https://docs.cdp.coinbase.com/advanced-trade/docs/welcome](https://docs.cdp.coinbase.com/advanced-trade/docs/welcome)
+ *
+ * The version of the OpenAPI document: 3.0
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ListAccountsResponse {
+    #[serde(rename = "accounts")]
+    pub accounts: Vec<models::Account>,
+    /// Whether there are additional pages for this query
+    #[serde(rename = "has_next")]
+    pub has_next: bool,
+    /// Cursor for paginating. Users can use this string to pass in the next call to this endpoint, and repeat this process to fetch all accounts through pagination
+    #[serde(rename = "cursor", skip_serializing_if = "Option::is_none")]
+    pub cursor: Option<String>,
+    /// Number of accounts returned
+    #[serde(rename = "size", skip_serializing_if = "Option::is_none")]
+    pub size: Option<i32>,
+}
+
+impl ListAccountsResponse {
+    pub fn new(accounts: Vec<models::Account>, has_next: bool) -> ListAccountsResponse {
+        ListAccountsResponse {
+            accounts,
+            has_next,
+            cursor: None,
+            size: None,
+        }
+    }
+}
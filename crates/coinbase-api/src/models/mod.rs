@@ -0,0 +1,10 @@
+pub mod account;
+pub use account::Account;
+pub mod amount;
+pub use amount::Amount;
+pub mod error;
+pub use error::Error;
+pub mod list_accounts_response;
+pub use list_accounts_response::ListAccountsResponse;
+pub mod product;
+pub use product::Product;
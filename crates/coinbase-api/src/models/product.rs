@@ -0,0 +1,34 @@
+/*
+ * Coinbase Advanced Trade API
+ *
+ * Client for the Coinbase Advanced Trade API  API documents:   - [https://docs.cdp.coinbase.com/advanced-trade/docs/welcome](https://docs.cdp.coinbase.com/advanced-trade/docs/welcome)
+ *
+ * The version of the OpenAPI document: 3.0
+ */
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Product {
+    /// The trading pair, e.g. `BTC-USD`
+    #[serde(rename = "product_id")]
+    pub product_id: String,
+    /// The current price for the product, in quote currency
+    #[serde(rename = "price")]
+    pub price: String,
+    #[serde(rename = "base_currency_id", skip_serializing_if = "Option::is_none")]
+    pub base_currency_id: Option<String>,
+    #[serde(rename = "quote_currency_id", skip_serializing_if = "Option::is_none")]
+    pub quote_currency_id: Option<String>,
+}
+
+impl Product {
+    pub fn new(product_id: String, price: String) -> Product {
+        Product {
+            product_id,
+            price,
+            base_currency_id: None,
+            quote_currency_id: None,
+        }
+    }
+}
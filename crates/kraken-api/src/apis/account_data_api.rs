@@ -0,0 +1,110 @@
+/*
+ * Kraken Spot REST API
+ *
+ * Client for the Kraken Spot REST API  API documents:   - [https://docs.kraken.com/api/](https://docs.kraken.com/api/)
+ *
+ * The version of the OpenAPI document: 1.1.0
+ */
+use super::{auth, configuration, Error};
+use crate::{apis::ResponseContent, models};
+use reqwest;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+
+/// struct for typed errors of method [`get_account_balance`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum GetAccountBalanceError {
+    Status200(models::Error),
+    UnknownValue(serde_json::Value),
+}
+
+/// struct for typed errors of method [`get_extended_balance`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum GetExtendedBalanceError {
+    Status200(models::Error),
+    UnknownValue(serde_json::Value),
+}
+
+/// Sends a signed request to a private endpoint. Kraken reports most errors
+/// with a 200 status, so a response with a non-empty `error` array is
+/// returned as a `ResponseError` too.
+async fn private_request<R, E>(
+    configuration: &configuration::Configuration,
+    api_secret: &str,
+    path: &str,
+) -> Result<R, Error<E>>
+where
+    R: DeserializeOwned,
+    E: DeserializeOwned,
+{
+    let local_var_configuration = configuration;
+
+    let local_var_client = &local_var_configuration.client;
+
+    let local_var_uri_str = format!("{}{}", local_var_configuration.base_path, path);
+    let mut local_var_req_builder =
+        local_var_client.request(reqwest::Method::POST, local_var_uri_str.as_str());
+
+    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
+        local_var_req_builder =
+            local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
+    }
+
+    let local_var_nonce = auth::next_nonce();
+    let local_var_body = format!("nonce={local_var_nonce}");
+
+    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
+        local_var_req_builder = local_var_req_builder.header("API-Key", &local_var_apikey.key);
+    }
+    local_var_req_builder = local_var_req_builder.header(
+        "API-Sign",
+        auth::calculate_signature(path, local_var_nonce, &local_var_body, api_secret)?,
+    );
+    local_var_req_builder = local_var_req_builder
+        .header(
+            reqwest::header::CONTENT_TYPE,
+            "application/x-www-form-urlencoded",
+        )
+        .body(local_var_body);
+
+    let local_var_req = local_var_req_builder.build()?;
+    let local_var_resp = local_var_client.execute(local_var_req).await?;
+
+    let local_var_status = local_var_resp.status();
+    let local_var_content = local_var_resp.text().await?;
+
+    let local_var_failed = local_var_status.is_client_error()
+        || local_var_status.is_server_error()
+        || serde_json::from_str::<models::Error>(&local_var_content)
+            .is_ok_and(|e| !e.error.is_empty());
+
+    if !local_var_failed {
+        serde_json::from_str(&local_var_content).map_err(Error::from)
+    } else {
+        let local_var_entity: Option<E> = serde_json::from_str(&local_var_content).ok();
+        let local_var_error = ResponseContent {
+            status: local_var_status,
+            content: local_var_content,
+            entity: local_var_entity,
+        };
+        Err(Error::ResponseError(local_var_error))
+    }
+}
+
+/// Retrieve all cash balances, net of pending withdrawals. Balances of staked
+/// and earning assets are reported under their own asset codes, e.g. `DOT.S`.
+pub async fn get_account_balance(
+    configuration: &configuration::Configuration,
+    api_secret: &str,
+) -> Result<models::BalanceResponse, Error<GetAccountBalanceError>> {
+    private_request(configuration, api_secret, "/0/private/Balance").await
+}
+
+/// Retrieve all extended account balances, including credits and held amounts.
+pub async fn get_extended_balance(
+    configuration: &configuration::Configuration,
+    api_secret: &str,
+) -> Result<models::BalanceExResponse, Error<GetExtendedBalanceError>> {
+    private_request(configuration, api_secret, "/0/private/BalanceEx").await
+}
@@ -0,0 +1,39 @@
+/*
+ * Kraken Spot REST API
+ *
+ * Client for the Kraken Spot REST API  API documents:   - [https://docs.kraken.com/api/](https://docs.kraken.com/api/)
+ *
+ * The version of the OpenAPI document: 1.1.0
+ */
+
+#[derive(Debug, Clone)]
+pub struct Configuration {
+    pub base_path: String,
+    pub user_agent: Option<String>,
+    pub client: reqwest::Client,
+    pub api_key: Option<ApiKey>,
+}
+
+#[derive(Debug, Clone)]
+pub struct ApiKey {
+    pub key: String,
+}
+
+impl Configuration {
+    pub fn new() -> Configuration {
+        Configuration::default()
+    }
+}
+
+impl Default for Configuration {
+    fn default() -> Self {
+        Configuration {
+            base_path: "https://api.kraken.com".to_owned(),
+            user_agent: Some(
+                concat!(env!("CARGO_PKG_NAME"), "/", env!("CARGO_PKG_VERSION")).to_owned(),
+            ),
+            client: reqwest::Client::new(),
+            api_key: None,
+        }
+    }
+}
@@ -0,0 +1,65 @@
+/*
+ * Kraken Spot REST API
+ *
+ * Client for the Kraken Spot REST API  API documents:   - [https://docs.kraken.com/api/](https://docs.kraken.com/api/)
+ *
+ * The version of the OpenAPI document: 1.1.0
+ */
+use super::{configuration, Error};
+use crate::{apis::ResponseContent, models};
+use reqwest;
+use serde::{Deserialize, Serialize};
+
+/// struct for typed errors of method [`get_ticker_information`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum GetTickerInformationError {
+    Status200(models::Error),
+    UnknownValue(serde_json::Value),
+}
+
+/// Get ticker information for a pair, e.g. `XBTUSD`. The result is keyed by
+/// Kraken's name for the pair, which may differ from the one requested. Does
+/// not require authentication.
+pub async fn get_ticker_information(
+    configuration: &configuration::Configuration,
+    pair: &str,
+) -> Result<models::TickerResponse, Error<GetTickerInformationError>> {
+    let local_var_configuration = configuration;
+
+    let local_var_client = &local_var_configuration.client;
+
+    let local_var_uri_str = format!("{}/0/public/Ticker", local_var_configuration.base_path);
+    let mut local_var_req_builder =
+        local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
+
+    local_var_req_builder = local_var_req_builder.query(&[("pair", &pair.to_string())]);
+    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
+        local_var_req_builder =
+            local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
+    }
+
+    let local_var_req = local_var_req_builder.build()?;
+    let local_var_resp = local_var_client.execute(local_var_req).await?;
+
+    let local_var_status = local_var_resp.status();
+    let local_var_content = local_var_resp.text().await?;
+
+    let local_var_failed = local_var_status.is_client_error()
+        || local_var_status.is_server_error()
+        || serde_json::from_str::<models::Error>(&local_var_content)
+            .is_ok_and(|e| !e.error.is_empty());
+
+    if !local_var_failed {
+        serde_json::from_str(&local_var_content).map_err(Error::from)
+    } else {
+        let local_var_entity: Option<GetTickerInformationError> =
+            serde_json::from_str(&local_var_content).ok();
+        let local_var_error = ResponseContent {
+            status: local_var_status,
+            content: local_var_content,
+            entity: local_var_entity,
+        };
+        Err(Error::ResponseError(local_var_error))
+    }
+}
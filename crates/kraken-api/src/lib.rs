@@ -0,0 +1,8 @@
+#![allow(clippy::too_many_arguments)]
+
+extern crate reqwest;
+extern crate serde;
+extern crate serde_json;
+
+pub mod apis;
+pub mod models;
@@ -0,0 +1,29 @@
+/*
+ * Kraken Spot REST API
+ *
+ * Client for the Kraken Spot REST API  API documents:   - [https://docs.kraken.com/api/](https://docs.kraken.com/api/)
+ *
+ * The version of the OpenAPI document: 1.1.0
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct BalanceExResponse {
+    #[serde(rename = "error")]
+    pub error: Vec<String>,
+    /// Extended balance by asset code, e.g. `XXBT` or `DOT.S`
+    #[serde(rename = "result", default)]
+    pub result: HashMap<String, models::ExtendedBalance>,
+}
+
+impl BalanceExResponse {
+    pub fn new(
+        error: Vec<String>,
+        result: HashMap<String, models::ExtendedBalance>,
+    ) -> BalanceExResponse {
+        BalanceExResponse { error, result }
+    }
+}
@@ -0,0 +1,25 @@
+/*
+ * Kraken Spot REST API
+ *
+ * Client for the Kraken Spot REST API  API documents:   - [https://docs.kraken.com/api/](https://docs.kraken.com/api/)
+ *
+ * The version of the OpenAPI document: 1.1.0
+ */
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct BalanceResponse {
+    #[serde(rename = "error")]
+    pub error: Vec<String>,
+    /// Balance by asset code, e.g. `XXBT` or `DOT.S`
+    #[serde(rename = "result", default)]
+    pub result: HashMap<String, String>,
+}
+
+impl BalanceResponse {
+    pub fn new(error: Vec<String>, result: HashMap<String, String>) -> BalanceResponse {
+        BalanceResponse { error, result }
+    }
+}
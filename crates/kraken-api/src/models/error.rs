@@ -0,0 +1,22 @@
+/*
+ * Kraken Spot REST API
+ *
+ * Client for the Kraken Spot REST API  API documents:   - [https://docs.kraken.com/api/](https://docs.kraken.com/api/)
+ *
+ * The version of the OpenAPI document: 1.1.0
+ */
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Error {
+    /// Error messages, e.g. `EAPI:Invalid key`
+    #[serde(rename = "error")]
+    pub error: Vec<String>,
+}
+
+impl Error {
+    pub fn new(error: Vec<String>) -> Error {
+        Error { error }
+    }
+}
@@ -0,0 +1,36 @@
+/*
+ * Kraken Spot REST API
+ *
+ * Client for the Kraken Spot REST API  API documents:   - [https://docs.kraken.com/api/](https://docs.kraken.com/api/)
+ *
+ * The version of the OpenAPI document: 1.1.0
+ */
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ExtendedBalance {
+    /// Total balance of the asset, including held amounts
+    #[serde(rename = "balance")]
+    pub balance: String,
+    /// Credit available
+    #[serde(rename = "credit", skip_serializing_if = "Option::is_none")]
+    pub credit: Option<String>,
+    /// Amount of credit used
+    #[serde(rename = "credit_used", skip_serializing_if = "Option::is_none")]
+    pub credit_used: Option<String>,
+    /// Amount held in open orders
+    #[serde(rename = "hold_trade", skip_serializing_if = "Option::is_none")]
+    pub hold_trade: Option<String>,
+}
+
+impl ExtendedBalance {
+    pub fn new(balance: String) -> ExtendedBalance {
+        ExtendedBalance {
+            balance,
+            credit: None,
+            credit_used: None,
+            hold_trade: None,
+        }
+    }
+}
@@ -0,0 +1,12 @@
+pub mod balance_ex_response;
+pub use balance_ex_response::BalanceExResponse;
+pub mod balance_response;
+pub use balance_response::BalanceResponse;
+pub mod error;
+pub use error::Error;
+pub mod extended_balance;
+pub use extended_balance::ExtendedBalance;
+pub mod ticker_info;
+pub use ticker_info::TickerInfo;
+pub mod ticker_response;
+pub use ticker_response::TickerResponse;
@@ -0,0 +1,37 @@
+/*
+ * Kraken Spot REST API
+ *
+ * Client for the Kraken Spot REST API  API documents:   - [https://docs.kraken.com/api/](https://docs.kraken.com/api/)
+ *
+ * The version of the OpenAPI document: 1.1.0
+ */
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct TickerInfo {
+    /// Ask `[price, whole lot volume, lot volume]`
+    #[serde(rename = "a", default)]
+    pub a: Vec<String>,
+    /// Bid `[price, whole lot volume, lot volume]`
+    #[serde(rename = "b", default)]
+    pub b: Vec<String>,
+    /// Last trade closed `[price, lot volume]`
+    #[serde(rename = "c", default)]
+    pub c: Vec<String>,
+}
+
+impl TickerInfo {
+    pub fn new(c: Vec<String>) -> TickerInfo {
+        TickerInfo {
+            a: vec![],
+            b: vec![],
+            c,
+        }
+    }
+
+    /// Price of the last closed trade.
+    pub fn last_price(&self) -> Option<&str> {
+        self.c.first().map(String::as_str)
+    }
+}
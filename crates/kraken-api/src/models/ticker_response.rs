@@ -0,0 +1,26 @@
+/*
+ * Kraken Spot REST API
+ *
+ * Client for the Kraken Spot REST API  API documents:   - [https://docs.kraken.com/api/](https://docs.kraken.com/api/)
+ *
+ * The version of the OpenAPI document: 1.1.0
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct TickerResponse {
+    #[serde(rename = "error")]
+    pub error: Vec<String>,
+    /// Ticker by pair name, e.g. `XXBTZUSD`
+    #[serde(rename = "result", default)]
+    pub result: HashMap<String, models::TickerInfo>,
+}
+
+impl TickerResponse {
+    pub fn new(error: Vec<String>, result: HashMap<String, models::TickerInfo>) -> TickerResponse {
+        TickerResponse { error, result }
+    }
+}
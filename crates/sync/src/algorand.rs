@@ -0,0 +1,232 @@
+use crate::http;
+use crate::prices::get_price_usd;
+use crate::report::{AssetValue, WalletValue};
+use data_encoding::BASE32_NOPAD;
+use serde::Deserialize;
+use sha2::{Digest, Sha512_256};
+use std::collections::HashMap;
+use std::error::Error;
+use tracing::{info, warn};
+
+const DEFAULT_ALGORAND_API_URL: &str = "https://mainnet-api.algonode.cloud";
+const ADDRESS_LENGTH: usize = 58;
+const CHECKSUM_LENGTH: usize = 4;
+const MICROALGOS_PER_ALGO: f64 = 1_000_000.0;
+
+/// Mainnet ASAs valued as the coin they track. Anyone can create an ASA with
+/// any unit name, so other assets are only valued when listed in
+/// `ALGORAND_ASSETS`.
+const VERIFIED_ASSETS: [(u64, &str); 4] = [
+    (31566704, "USDC"),
+    (312769, "USDT"),
+    (386192725, "BTC"),
+    (386195940, "ETH"),
+];
+
+#[derive(Debug, Deserialize)]
+struct Account {
+    amount: u64,
+    #[serde(default)]
+    assets: Vec<AssetHolding>,
+}
+
+#[derive(Debug, Deserialize)]
+struct AssetHolding {
+    #[serde(rename = "asset-id")]
+    asset_id: u64,
+    amount: u64,
+}
+
+#[derive(Debug, Deserialize)]
+struct Asset {
+    params: AssetParams,
+}
+
+#[derive(Debug, Deserialize)]
+struct AssetParams {
+    decimals: u32,
+    #[serde(rename = "unit-name")]
+    unit_name: Option<String>,
+}
+
+/// algod returns the account itself, the indexer wraps it with the round it
+/// was read at.
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum AccountResponse {
+    Indexer { account: Account },
+    Algod(Account),
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum AssetResponse {
+    Indexer { asset: Asset },
+    Algod(Asset),
+}
+
+/// Returns whether `address` is a valid Algorand address: the base32 encoded
+/// public key followed by the last 4 bytes of its SHA-512/256 hash.
+pub fn is_valid_address(address: &str) -> bool {
+    if address.len() != ADDRESS_LENGTH {
+        return false;
+    }
+
+    let Ok(decoded) = BASE32_NOPAD.decode(address.as_bytes()) else {
+        return false;
+    };
+
+    let (public_key, checksum) = decoded.split_at(decoded.len() - CHECKSUM_LENGTH);
+    let hash = Sha512_256::digest(public_key);
+
+    hash[hash.len() - CHECKSUM_LENGTH..] == *checksum
+}
+
+fn api_url() -> String {
+    std::env::var("ALGORAND_API_URL")
+        .ok()
+        .filter(|u| !u.is_empty())
+        .unwrap_or_else(|| DEFAULT_ALGORAND_API_URL.to_string())
+        .trim_end_matches('/')
+        .to_string()
+}
+
+/// ASAs to value as a coin, from `VERIFIED_ASSETS` and `ALGORAND_ASSETS`, a
+/// comma-separated list of `asset-id:SYMBOL` pairs.
+fn known_assets() -> HashMap<u64, String> {
+    let mut assets: HashMap<u64, String> = VERIFIED_ASSETS
+        .iter()
+        .map(|(id, symbol)| (*id, symbol.to_string()))
+        .collect();
+
+    for entry in std::env::var("ALGORAND_ASSETS")
+        .unwrap_or_default()
+        .split(',')
+    {
+        match entry.split_once(':') {
+            Some((id, symbol)) => match id.trim().parse() {
+                Ok(id) => {
+                    assets.insert(id, symbol.trim().to_uppercase());
+                }
+                Err(_) => warn!("Invalid Algorand asset id in ALGORAND_ASSETS: {id}"),
+            },
+            None if entry.trim().is_empty() => {}
+            None => warn!("Invalid ALGORAND_ASSETS entry: {entry}"),
+        }
+    }
+
+    assets
+}
+
+async fn get<T: for<'de> Deserialize<'de>>(
+    client: &reqwest::Client,
+    url: String,
+) -> Result<T, Box<dyn Error + Send + Sync>> {
+    let mut request = client.get(url);
+
+    if let Some(token) = std::env::var("ALGORAND_API_TOKEN")
+        .ok()
+        .filter(|t| !t.is_empty())
+    {
+        request = request
+            .header("X-Algo-API-Token", &token)
+            .header("X-Indexer-API-Token", &token);
+    }
+
+    Ok(request
+        .send()
+        .await?
+        .error_for_status()?
+        .json::<T>()
+        .await?)
+}
+
+pub async fn get_algorand_wallet_value(
+    wallet: &str,
+) -> Result<Option<WalletValue>, Box<dyn Error + Send + Sync>> {
+    let client = http::client();
+    let base = api_url();
+
+    let account = match get(&client, format!("{base}/v2/accounts/{wallet}")).await? {
+        AccountResponse::Indexer { account } => account,
+        AccountResponse::Algod(account) => account,
+    };
+
+    let algo_price = get_price_usd("ALGO")
+        .await?
+        .ok_or("No price found for ALGO")?;
+
+    let mut assets = vec![AssetValue::new(
+        "ALGO",
+        account.amount as f64 / MICROALGOS_PER_ALGO,
+        algo_price,
+    )];
+
+    let known = known_assets();
+    let mut unpriced = vec![];
+
+    for holding in account.assets.iter().filter(|h| h.amount > 0) {
+        let params = match get(&client, format!("{base}/v2/assets/{}", holding.asset_id)).await? {
+            AssetResponse::Indexer { asset } => asset.params,
+            AssetResponse::Algod(asset) => asset.params,
+        };
+
+        let quantity = holding.amount as f64 / 10f64.powi(params.decimals as i32);
+        let unit_name = params.unit_name.unwrap_or_default();
+
+        let Some(symbol) = known.get(&holding.asset_id) else {
+            info!(
+                "Skipping unverified Algorand asset {} ({unit_name}) held by {wallet}",
+                holding.asset_id
+            );
+            continue;
+        };
+
+        match get_price_usd(symbol).await? {
+            Some(price) => assets.push(AssetValue::new(symbol, quantity, price)),
+            None => {
+                warn!(
+                    "No price found for Algorand asset {} ({unit_name})",
+                    holding.asset_id
+                );
+                unpriced.push(symbol.clone());
+            }
+        }
+    }
+
+    Ok(Some(
+        WalletValue::from_assets(assets).with_unpriced(unpriced),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn accepts_addresses_with_a_valid_checksum() {
+        for address in [
+            "AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAY5HFKQ",
+            "737777777777777777777777777777777777777777777777777UFEJ2CI",
+            "Y76M3MSY6DKBRHBL7C3NNDXGS5IIMQVQVUAB6MP4XEMMGVF2QWNPL226CA",
+        ] {
+            assert!(is_valid_address(address), "{address} should be valid");
+        }
+    }
+
+    #[test]
+    fn rejects_bad_checksums_and_malformed_addresses() {
+        for address in [
+            // one character off
+            "Y77M3MSY6DKBRHBL7C3NNDXGS5IIMQVQVUAB6MP4XEMMGVF2QWNPL226CA",
+            "AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAY5HFKA",
+            // lowercase, truncated and not base32
+            "y76m3msy6dkbrhbl7c3nndxgs5iimqvqvuab6mp4xemmgvf2qwnpl226ca",
+            "Y76M3MSY6DKBRHBL7C3NNDXGS5IIMQVQVUAB6MP4XEMMGVF2QWNPL226C",
+            "Y76M3MSY6DKBRHBL7C3NNDXGS5IIMQVQVUAB6MP4XEMMGVF2QWNPL22681",
+            "",
+        ] {
+            assert!(!is_valid_address(address), "{address} should be invalid");
+        }
+    }
+}
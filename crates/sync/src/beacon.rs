@@ -0,0 +1,92 @@
+use crate::http;
+use crate::prices::get_price_usd;
+use crate::report::{AssetValue, WalletValue};
+use serde::Deserialize;
+use std::env;
+use std::error::Error;
+use tracing::{info, warn};
+
+const BEACON_API_URL: &str = "https://ethereum-beacon-api.publicnode.com";
+/// Validator ids per request, to keep the query string short.
+const IDS_PER_REQUEST: usize = 50;
+const GWEI_PER_ETH: f64 = 1_000_000_000.0;
+
+/// Name of the wallet the validators are reported as, and of its YNAB payee.
+pub const VALIDATORS_WALLET: &str = "ETH validators";
+
+/// Checks a validator index or a 48 byte hex pubkey.
+pub fn is_valid_validator_id(id: &str) -> bool {
+    match id.strip_prefix("0x") {
+        Some(pubkey) => pubkey.len() == 96 && pubkey.chars().all(|c| c.is_ascii_hexdigit()),
+        None => !id.is_empty() && id.parse::<u64>().is_ok(),
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct ValidatorsResponse {
+    data: Vec<Validator>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Validator {
+    index: String,
+    /// Balance in gwei.
+    balance: String,
+    status: String,
+}
+
+/// Values the given validators, by index or pubkey, from their balance on the
+/// head state of the Beacon API node at `BEACON_API_URL`.
+pub async fn get_validators_value(
+    ids: &[String],
+) -> Result<Option<WalletValue>, Box<dyn Error + Send + Sync>> {
+    let url = env::var("BEACON_API_URL")
+        .ok()
+        .filter(|u| !u.is_empty())
+        .unwrap_or_else(|| BEACON_API_URL.to_string());
+
+    let client = http::client();
+    let mut gwei = 0;
+    let mut found = 0;
+
+    for chunk in ids.chunks(IDS_PER_REQUEST) {
+        let response = client
+            .get(format!(
+                "{}/eth/v1/beacon/states/head/validators",
+                url.trim_end_matches('/')
+            ))
+            .query(&[("id", chunk.join(","))])
+            .send()
+            .await?
+            .error_for_status()?
+            .json::<ValidatorsResponse>()
+            .await?;
+
+        for validator in response.data {
+            // exited validators keep their balance until it is withdrawn
+            info!(
+                "Validator {} is {} with {} gwei",
+                validator.index, validator.status, validator.balance
+            );
+            gwei += validator.balance.parse::<u64>()?;
+            found += 1;
+        }
+    }
+
+    if found < ids.len() {
+        warn!(
+            "Only {found} of {} validators were found on the beacon node",
+            ids.len()
+        );
+    }
+
+    let price = get_price_usd("ETH")
+        .await?
+        .ok_or("No price found for ETH")?;
+
+    Ok(Some(WalletValue::from_assets(vec![AssetValue::new(
+        "ETH",
+        gwei as f64 / GWEI_PER_ETH,
+        price,
+    )])))
+}
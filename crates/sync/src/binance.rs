@@ -1,33 +1,167 @@
-use crate::bitcoin::get_bitcoin_price_usd;
+use crate::cex::{Balance, Exchange, ExchangeError};
+use crate::http;
+use crate::oauth;
+use async_trait::async_trait;
 use binance_api::apis::configuration::{ApiKey, Configuration};
-use binance_api::apis::wallet_api::sapi_v1_asset_wallet_balance_get;
-use std::error::Error;
+use binance_api::apis::market_api::api_v3_ticker_price_get;
+use binance_api::apis::simple_earn_api::{
+    sapi_v1_simple_earn_flexible_position_get, sapi_v1_simple_earn_locked_position_get,
+};
+use binance_api::apis::trade_api::api_v3_account_get;
+use binance_api::apis::wallet_api::{
+    sapi_v1_asset_get_funding_asset_post, sapi_v1_asset_wallet_balance_get,
+};
+use tracing::error;
 
-fn config() -> Configuration {
-    let mut config = Configuration::default();
+const PAGE_SIZE: i32 = 100;
 
-    config.api_key = Some(ApiKey {
-        key: std::env::var("BINANCE_API_KEY").expect("Binance API key should be set"),
-        prefix: None,
-    });
+/// Stablecoins binance quotes other assets in, valued at their peg.
+const USD_STABLECOINS: [&str; 4] = ["USDT", "USDC", "FDUSD", "TUSD"];
 
-    config
+/// Wallets whose balances are read asset by asset. Binance only reports the
+/// total of the others, e.g. margin, futures and trading bot wallets.
+const ITEMIZED_WALLETS: [&str; 3] = ["Spot", "Funding", "Earn"];
+
+pub struct Binance {
+    config: Configuration,
+    secret_key: String,
+}
+
+impl Binance {
+    /// Returns the total of every wallet that is not itemized, as the BTC it is
+    /// worth.
+    async fn other_wallet_balances(&self) -> Result<Vec<Balance>, ExchangeError> {
+        let wallets =
+            sapi_v1_asset_wallet_balance_get(&self.config, &self.secret_key, None).await?;
+
+        wallets
+            .into_iter()
+            .filter(|w| !ITEMIZED_WALLETS.contains(&w.wallet_name.as_str()))
+            .map(|w| Ok(Balance::new("BTC", w.balance.parse()?)))
+            .collect()
+    }
 }
 
-pub async fn get_binance_wallet_value() -> Result<f64, Box<dyn Error + Send + Sync>> {
-    let secret_key = std::env::var("BINANCE_SECRET_KEY").expect("Binance secret key should be set");
+#[async_trait]
+impl Exchange for Binance {
+    fn name(&self) -> &'static str {
+        "Binance"
+    }
+
+    fn from_env(user: Option<&str>) -> Option<Self> {
+        let key = oauth::user_var("BINANCE_API_KEY", user)?;
+
+        let mut config = Configuration {
+            api_key: Some(ApiKey { key, prefix: None }),
+            client: http::client(),
+            user_agent: Some(http::user_agent()),
+            ..Default::default()
+        };
+
+        if let Some(base_path) = std::env::var("BINANCE_API_URL")
+            .ok()
+            .filter(|u| !u.is_empty())
+        {
+            config.base_path = base_path.trim_end_matches('/').to_string();
+        }
+
+        let Some(secret_key) = oauth::user_var("BINANCE_SECRET_KEY", user) else {
+            error!("Binance API key set without its secret, skipping Binance");
+            return None;
+        };
+
+        Some(Binance { config, secret_key })
+    }
+
+    async fn balances(&self) -> Result<Vec<Balance>, ExchangeError> {
+        let mut balances = self.spot_balances().await?;
+        balances.extend(self.earn_balances().await?);
+        balances.extend(self.other_wallet_balances().await?);
+        Ok(balances)
+    }
+
+    async fn spot_balances(&self) -> Result<Vec<Balance>, ExchangeError> {
+        let account = api_v3_account_get(&self.config, &self.secret_key, Some(true), None).await?;
+        let funding =
+            sapi_v1_asset_get_funding_asset_post(&self.config, &self.secret_key, None, None, None)
+                .await?;
+
+        let mut balances = vec![];
+
+        for balance in account.balances {
+            // flexible earn positions are mirrored in the spot account as
+            // `LD` prefixed assets and counted with the earn balances instead
+            if balance.asset.len() > 3 && balance.asset.starts_with("LD") {
+                continue;
+            }
+
+            let quantity = balance.free.parse::<f64>()? + balance.locked.parse::<f64>()?;
+            balances.push(Balance::new(&balance.asset, quantity));
+        }
+
+        for balance in funding {
+            let quantity = balance.free.parse::<f64>()?
+                + balance.locked.parse::<f64>()?
+                + balance.freeze.parse::<f64>()?;
+            balances.push(Balance::new(&balance.asset, quantity));
+        }
+
+        Ok(balances)
+    }
+
+    async fn earn_balances(&self) -> Result<Vec<Balance>, ExchangeError> {
+        let mut balances = vec![];
+
+        for current in 1.. {
+            let page = sapi_v1_simple_earn_flexible_position_get(
+                &self.config,
+                &self.secret_key,
+                None,
+                Some(current),
+                Some(PAGE_SIZE),
+                None,
+            )
+            .await?;
+
+            let last = page.rows.len() < PAGE_SIZE as usize;
+            for row in page.rows {
+                balances.push(Balance::new(&row.asset, row.total_amount.parse()?));
+            }
+            if last {
+                break;
+            }
+        }
+
+        for current in 1.. {
+            let page = sapi_v1_simple_earn_locked_position_get(
+                &self.config,
+                &self.secret_key,
+                None,
+                Some(current),
+                Some(PAGE_SIZE),
+                None,
+            )
+            .await?;
+
+            let last = page.rows.len() < PAGE_SIZE as usize;
+            for row in page.rows {
+                balances.push(Balance::new(&row.asset, row.amount.parse()?));
+            }
+            if last {
+                break;
+            }
+        }
 
-    let balance: f64 = {
-        let balances =
-            sapi_v1_asset_wallet_balance_get(&config(), secret_key.as_str(), None).await?;
+        Ok(balances)
+    }
 
-        balances
-            .iter()
-            .map(|b| b.balance.parse::<f64>().unwrap())
-            .sum()
-    };
+    async fn price_usd(&self, symbol: &str) -> Result<f64, ExchangeError> {
+        if USD_STABLECOINS.contains(&symbol) {
+            return Ok(1.0);
+        }
 
-    let bitcoin_price = get_bitcoin_price_usd().await?;
+        let ticker = api_v3_ticker_price_get(&self.config, &format!("{symbol}USDT")).await?;
 
-    Ok(balance * bitcoin_price)
+        Ok(ticker.price.parse()?)
+    }
 }
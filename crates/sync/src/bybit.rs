@@ -0,0 +1,122 @@
+use crate::cex::{Balance, Exchange, ExchangeError};
+use crate::http;
+use crate::oauth;
+use async_trait::async_trait;
+use bybit_api::apis::account_api::get_wallet_balance;
+use bybit_api::apis::asset_api::get_all_coins_balance;
+use bybit_api::apis::configuration::{ApiKey, Configuration};
+use bybit_api::apis::earn_api::get_earn_position;
+use bybit_api::apis::market_api::get_tickers;
+use tracing::error;
+
+/// Earn products whose positions are held outside the wallets.
+const EARN_CATEGORIES: [&str; 2] = ["FlexibleSaving", "OnChain"];
+
+/// Stablecoins bybit quotes other assets in, valued at their peg.
+const USD_STABLECOINS: [&str; 2] = ["USDT", "USDC"];
+
+pub struct Bybit {
+    config: Configuration,
+    api_secret: String,
+}
+
+#[async_trait]
+impl Exchange for Bybit {
+    fn name(&self) -> &'static str {
+        "Bybit"
+    }
+
+    fn from_env(user: Option<&str>) -> Option<Self> {
+        let key = oauth::user_var("BYBIT_API_KEY", user)?;
+
+        let mut config = Configuration {
+            api_key: Some(ApiKey { key }),
+            client: http::client(),
+            user_agent: Some(http::user_agent()),
+            ..Default::default()
+        };
+
+        if let Some(base_path) = std::env::var("BYBIT_API_URL")
+            .ok()
+            .filter(|u| !u.is_empty())
+        {
+            config.base_path = base_path.trim_end_matches('/').to_string();
+        }
+
+        let Some(api_secret) = oauth::user_var("BYBIT_API_SECRET", user) else {
+            error!("Bybit API key set without its secret, skipping Bybit");
+            return None;
+        };
+
+        Some(Bybit { config, api_secret })
+    }
+
+    async fn spot_balances(&self) -> Result<Vec<Balance>, ExchangeError> {
+        let unified = get_wallet_balance(&self.config, &self.api_secret, "UNIFIED", None).await?;
+        let funding = get_all_coins_balance(&self.config, &self.api_secret, "FUND", None).await?;
+
+        let mut balances = vec![];
+
+        for account in unified.result.into_iter().flat_map(|r| r.list) {
+            for coin in account.coin {
+                balances.push(Balance::new(
+                    &coin.coin,
+                    parse_amount(&coin.wallet_balance)?,
+                ));
+            }
+        }
+
+        for coin in funding.result.into_iter().flat_map(|r| r.balance) {
+            balances.push(Balance::new(
+                &coin.coin,
+                parse_amount(&coin.wallet_balance)?,
+            ));
+        }
+
+        Ok(balances)
+    }
+
+    async fn earn_balances(&self) -> Result<Vec<Balance>, ExchangeError> {
+        let mut balances = vec![];
+
+        for category in EARN_CATEGORIES {
+            let positions =
+                get_earn_position(&self.config, &self.api_secret, category, None).await?;
+
+            for position in positions.result.into_iter().flat_map(|r| r.list) {
+                balances.push(Balance::new(
+                    &position.coin,
+                    parse_amount(&position.amount)?,
+                ));
+            }
+        }
+
+        Ok(balances)
+    }
+
+    async fn price_usd(&self, symbol: &str) -> Result<f64, ExchangeError> {
+        if USD_STABLECOINS.contains(&symbol) {
+            return Ok(1.0);
+        }
+
+        let tickers = get_tickers(&self.config, "spot", &format!("{symbol}USDT")).await?;
+
+        let ticker = tickers
+            .result
+            .into_iter()
+            .flat_map(|r| r.list)
+            .next()
+            .ok_or_else(|| format!("No {symbol}USDT ticker on Bybit"))?;
+
+        Ok(ticker.last_price.parse()?)
+    }
+}
+
+/// Parses an amount, which bybit sends as an empty string when there is none.
+fn parse_amount(amount: &str) -> Result<f64, ExchangeError> {
+    if amount.is_empty() {
+        return Ok(0.0);
+    }
+
+    Ok(amount.parse()?)
+}
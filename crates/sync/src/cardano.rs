@@ -0,0 +1,625 @@
+use crate::http;
+use crate::oauth;
+use crate::prices::get_price_usd;
+use crate::report::{AssetValue, WalletValue};
+use bech32::primitives::decode::CheckedHrpstring;
+use bech32::Bech32;
+use serde::Deserialize;
+use serde_json::json;
+use std::collections::HashMap;
+use std::error::Error;
+use tracing::{error, info, warn};
+
+const DEFAULT_BLOCKFROST_URL: &str = "https://cardano-mainnet.blockfrost.io/api/v0";
+const DEFAULT_KOIOS_URL: &str = "https://api.koios.rest/api/v1";
+const LOVELACE_PER_ADA: f64 = 1_000_000.0;
+const BLOCKFROST_PAGE_SIZE: usize = 100;
+/// Length of a stake address payload: a header byte and a 28 byte credential.
+const STAKE_ADDRESS_LENGTH: usize = 29;
+
+/// Mainnet native tokens valued as the coin they track, by policy id and hex
+/// encoded asset name. Anyone can mint a token with any name, so other tokens
+/// are only valued when listed in `CARDANO_ASSETS`.
+const VERIFIED_ASSETS: [(&str, &str, &str); 3] = [
+    (
+        "8db269c3ec630e06ae29f74bc39edd1f87c819f1056206e879a1cd61",
+        "446a65644d6963726f555344",
+        "DJED",
+    ),
+    (
+        "c48cbb3d5e57ed56e276bc45f99ab39abe94e6cd7ac39fb402da47ad",
+        "0014df105553444d",
+        "USDM",
+    ),
+    (
+        "f66d78b4a3cb3d37afa0ec36461e51ecbde00f26c8f0a68f94b69880",
+        "69555344",
+        "IUSD",
+    ),
+];
+
+/// DJED, USDM and iUSD track the US dollar.
+const USD_STABLECOINS: [&str; 3] = ["DJED", "USDM", "IUSD"];
+
+/// The network wallets are on, set with `CARDANO_NETWORK`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Network {
+    Mainnet,
+    /// The preprod and preview testnets, which share their address format.
+    Testnet,
+}
+
+impl Network {
+    /// Returns the configured network, or `None` for testnet without a
+    /// `CARDANO_API_URL`, as the default endpoints only serve mainnet.
+    fn from_env() -> Option<Self> {
+        match std::env::var("CARDANO_NETWORK").as_deref() {
+            Ok("testnet") => {
+                if std::env::var("CARDANO_API_URL").is_ok_and(|u| !u.is_empty()) {
+                    Some(Network::Testnet)
+                } else {
+                    error!("CARDANO_NETWORK is testnet but no testnet CARDANO_API_URL is set");
+                    None
+                }
+            }
+            _ => Some(Network::Mainnet),
+        }
+    }
+
+    /// The human-readable parts of payment and stake addresses.
+    fn hrps(self) -> (&'static str, &'static str) {
+        match self {
+            Network::Mainnet => ("addr", "stake"),
+            Network::Testnet => ("addr_test", "stake_test"),
+        }
+    }
+
+    /// The network id in the low bits of an address header.
+    fn id(self) -> u8 {
+        match self {
+            Network::Mainnet => 1,
+            Network::Testnet => 0,
+        }
+    }
+}
+
+/// Decodes a bech32 Shelley payment (`addr1…`) or stake (`stake1…`) address
+/// of `network` into its header byte and credentials.
+fn decode_address(address: &str, network: Network) -> Option<Vec<u8>> {
+    let checked = CheckedHrpstring::new::<Bech32>(address).ok()?;
+    let payload: Vec<u8> = checked.byte_iter().collect();
+    let header = *payload.first()?;
+
+    if header & 0x0f != network.id() {
+        return None;
+    }
+
+    let (payment_hrp, stake_hrp) = network.hrps();
+    let hrp = checked.hrp();
+    let valid = if hrp.as_str() == stake_hrp {
+        payload.len() == STAKE_ADDRESS_LENGTH && header >> 4 >= 0b1110
+    } else if hrp.as_str() == payment_hrp {
+        payload.len() >= STAKE_ADDRESS_LENGTH && header >> 4 <= 0b0111
+    } else {
+        false
+    };
+
+    valid.then_some(payload)
+}
+
+/// Returns the stake address, as bytes, controlling a decoded address. Only
+/// base and stake addresses carry it; pointer and enterprise addresses are
+/// valued on their own.
+fn stake_key(payload: &[u8]) -> Option<Vec<u8>> {
+    let header = payload[0];
+
+    match header >> 4 {
+        0b1110 | 0b1111 => Some(payload.to_vec()),
+        // base addresses: the payment then the stake credential, which is a
+        // script hash in types 2 and 3
+        kind @ 0..=3 if payload.len() == 1 + 2 * (STAKE_ADDRESS_LENGTH - 1) => {
+            let stake_kind = 0b1110 | (kind >> 1);
+            let mut key = vec![stake_kind << 4 | (header & 0x0f)];
+            key.extend_from_slice(&payload[STAKE_ADDRESS_LENGTH..]);
+            Some(key)
+        }
+        _ => None,
+    }
+}
+
+/// Returns a user's valid wallets in `CARDANO_WALLETS`. The holdings of an
+/// address are those of its whole stake key, so addresses sharing a stake key
+/// are only valued once, under the first of them.
+pub fn configured_wallets(user: Option<&str>) -> Vec<String> {
+    let Some(network) = Network::from_env() else {
+        return vec![];
+    };
+
+    let mut stake_keys: HashMap<Vec<u8>, String> = HashMap::new();
+    let mut wallets = vec![];
+
+    for wallet in oauth::user_var("CARDANO_WALLETS", user)
+        .unwrap_or_default()
+        .split(',')
+    {
+        let Some(payload) = decode_address(wallet, network) else {
+            continue;
+        };
+
+        if let Some(key) = stake_key(&payload) {
+            if let Some(first) = stake_keys.get(&key) {
+                warn!("Cardano wallet {wallet} shares its stake key with {first}, skipping it");
+                continue;
+            }
+            stake_keys.insert(key, wallet.to_string());
+        }
+
+        wallets.push(wallet.to_string());
+    }
+
+    wallets
+}
+
+fn is_stake_address(address: &str) -> bool {
+    address.starts_with("stake")
+}
+
+/// A native token held by a wallet.
+#[derive(Debug, Clone)]
+struct TokenHolding {
+    policy_id: String,
+    /// Hex encoded asset name.
+    asset_name: String,
+    quantity: f64,
+    decimals: Option<u32>,
+}
+
+/// Everything controlled by a stake key or, for addresses without one, by a
+/// single address.
+#[derive(Debug, Default)]
+struct Holdings {
+    /// Lovelace in the wallet's addresses plus unwithdrawn rewards.
+    lovelace: f64,
+    tokens: Vec<TokenHolding>,
+}
+
+/// A Blockfrost-compatible or Koios endpoint.
+enum Provider {
+    Blockfrost { url: String, project_id: String },
+    Koios { url: String, token: Option<String> },
+}
+
+#[derive(Debug, Deserialize)]
+struct BlockfrostAmount {
+    unit: String,
+    quantity: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct BlockfrostAddress {
+    amount: Vec<BlockfrostAmount>,
+    stake_address: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct BlockfrostAccount {
+    /// Includes the rewards that have not been withdrawn yet.
+    controlled_amount: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct BlockfrostAsset {
+    metadata: Option<BlockfrostAssetMetadata>,
+}
+
+#[derive(Debug, Deserialize)]
+struct BlockfrostAssetMetadata {
+    decimals: Option<u32>,
+}
+
+#[derive(Debug, Deserialize)]
+struct KoiosAddressInfo {
+    balance: String,
+    stake_address: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct KoiosAccountInfo {
+    /// Includes the rewards that have not been withdrawn yet.
+    total_balance: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct KoiosAsset {
+    policy_id: String,
+    asset_name: Option<String>,
+    decimals: Option<u32>,
+    quantity: String,
+}
+
+impl Provider {
+    fn from_env() -> Self {
+        let var = |name: &str| std::env::var(name).ok().filter(|v| !v.is_empty());
+        let url = var("CARDANO_API_URL").map(|u| u.trim_end_matches('/').to_string());
+
+        match var("CARDANO_PROVIDER").as_deref() {
+            Some("blockfrost") => Provider::Blockfrost {
+                url: url.unwrap_or_else(|| DEFAULT_BLOCKFROST_URL.to_string()),
+                project_id: var("CARDANO_API_KEY").unwrap_or_default(),
+            },
+            _ => Provider::Koios {
+                url: url.unwrap_or_else(|| DEFAULT_KOIOS_URL.to_string()),
+                token: var("CARDANO_API_KEY"),
+            },
+        }
+    }
+
+    async fn blockfrost_get<T: for<'de> Deserialize<'de>>(
+        &self,
+        client: &reqwest::Client,
+        path: &str,
+    ) -> Result<T, Box<dyn Error + Send + Sync>> {
+        let Provider::Blockfrost { url, project_id } = self else {
+            unreachable!("only called for blockfrost");
+        };
+
+        Ok(client
+            .get(format!("{url}{path}"))
+            .header("project_id", project_id)
+            .send()
+            .await?
+            .error_for_status()?
+            .json::<T>()
+            .await?)
+    }
+
+    async fn koios_post<T: for<'de> Deserialize<'de>>(
+        &self,
+        client: &reqwest::Client,
+        path: &str,
+        body: serde_json::Value,
+    ) -> Result<T, Box<dyn Error + Send + Sync>> {
+        let Provider::Koios { url, token } = self else {
+            unreachable!("only called for koios");
+        };
+
+        let mut request = client.post(format!("{url}{path}")).json(&body);
+
+        if let Some(token) = token {
+            request = request.bearer_auth(token);
+        }
+
+        Ok(request
+            .send()
+            .await?
+            .error_for_status()?
+            .json::<T>()
+            .await?)
+    }
+
+    /// Returns the holdings of the stake key behind an address, or of the
+    /// address itself if it has no stake key.
+    async fn holdings(
+        &self,
+        client: &reqwest::Client,
+        address: &str,
+    ) -> Result<Holdings, Box<dyn Error + Send + Sync>> {
+        match self {
+            Provider::Blockfrost { .. } => {
+                let stake_address = if is_stake_address(address) {
+                    address.to_string()
+                } else {
+                    let info: BlockfrostAddress = self
+                        .blockfrost_get(client, &format!("/addresses/{address}"))
+                        .await?;
+
+                    match info.stake_address {
+                        Some(stake_address) => stake_address,
+                        None => return blockfrost_holdings(info.amount),
+                    }
+                };
+
+                let account: BlockfrostAccount = self
+                    .blockfrost_get(client, &format!("/accounts/{stake_address}"))
+                    .await?;
+
+                let mut amounts = vec![];
+                for page in 1.. {
+                    let assets: Vec<BlockfrostAmount> = self
+                        .blockfrost_get(
+                            client,
+                            &format!(
+                                "/accounts/{stake_address}/addresses/assets?page={page}&count={BLOCKFROST_PAGE_SIZE}"
+                            ),
+                        )
+                        .await?;
+
+                    let last = assets.len() < BLOCKFROST_PAGE_SIZE;
+                    amounts.extend(assets);
+                    if last {
+                        break;
+                    }
+                }
+
+                let mut holdings = blockfrost_holdings(amounts)?;
+                holdings.lovelace = account.controlled_amount.parse()?;
+                Ok(holdings)
+            }
+            Provider::Koios { .. } => {
+                let stake_address = if is_stake_address(address) {
+                    Some(address.to_string())
+                } else {
+                    let info: Vec<KoiosAddressInfo> = self
+                        .koios_post(client, "/address_info", json!({ "_addresses": [address] }))
+                        .await?;
+
+                    match info.into_iter().next() {
+                        Some(KoiosAddressInfo {
+                            stake_address: Some(stake_address),
+                            ..
+                        }) => Some(stake_address),
+                        Some(info) => {
+                            let assets: Vec<KoiosAsset> = self
+                                .koios_post(
+                                    client,
+                                    "/address_assets",
+                                    json!({ "_addresses": [address] }),
+                                )
+                                .await?;
+
+                            return Ok(Holdings {
+                                lovelace: info.balance.parse()?,
+                                tokens: koios_tokens(assets)?,
+                            });
+                        }
+                        // addresses that never received funds are unknown
+                        None => return Ok(Holdings::default()),
+                    }
+                };
+
+                let stake_addresses = json!({ "_stake_addresses": stake_address.as_slice() });
+
+                let account: Vec<KoiosAccountInfo> = self
+                    .koios_post(client, "/account_info", stake_addresses.clone())
+                    .await?;
+                let assets: Vec<KoiosAsset> = self
+                    .koios_post(client, "/account_assets", stake_addresses)
+                    .await?;
+
+                Ok(Holdings {
+                    lovelace: match account.first() {
+                        Some(account) => account.total_balance.parse()?,
+                        None => 0.0,
+                    },
+                    tokens: koios_tokens(assets)?,
+                })
+            }
+        }
+    }
+
+    /// Returns the number of decimals of a token from its registry metadata.
+    async fn decimals(
+        &self,
+        client: &reqwest::Client,
+        token: &TokenHolding,
+    ) -> Result<u32, Box<dyn Error + Send + Sync>> {
+        if let Some(decimals) = token.decimals {
+            return Ok(decimals);
+        }
+
+        match self {
+            Provider::Blockfrost { .. } => {
+                let asset: BlockfrostAsset = self
+                    .blockfrost_get(
+                        client,
+                        &format!("/assets/{}{}", token.policy_id, token.asset_name),
+                    )
+                    .await?;
+
+                Ok(asset.metadata.and_then(|m| m.decimals).unwrap_or_default())
+            }
+            // koios includes the decimals with the holdings
+            Provider::Koios { .. } => Ok(0),
+        }
+    }
+}
+
+fn blockfrost_holdings(
+    amounts: Vec<BlockfrostAmount>,
+) -> Result<Holdings, Box<dyn Error + Send + Sync>> {
+    let mut holdings = Holdings::default();
+
+    for amount in amounts {
+        let quantity = amount.quantity.parse()?;
+
+        if amount.unit == "lovelace" {
+            holdings.lovelace += quantity;
+        } else {
+            // policy ids are 28 bytes, the asset name follows
+            let (policy_id, asset_name) = amount.unit.split_at(amount.unit.len().min(56));
+            holdings.tokens.push(TokenHolding {
+                policy_id: policy_id.to_string(),
+                asset_name: asset_name.to_string(),
+                quantity,
+                decimals: None,
+            });
+        }
+    }
+
+    Ok(holdings)
+}
+
+fn koios_tokens(
+    assets: Vec<KoiosAsset>,
+) -> Result<Vec<TokenHolding>, Box<dyn Error + Send + Sync>> {
+    assets
+        .into_iter()
+        .map(|asset| {
+            Ok(TokenHolding {
+                policy_id: asset.policy_id,
+                asset_name: asset.asset_name.unwrap_or_default(),
+                quantity: asset.quantity.parse()?,
+                decimals: Some(asset.decimals.unwrap_or_default()),
+            })
+        })
+        .collect()
+}
+
+/// Tokens to value as a coin, from `VERIFIED_ASSETS` and `CARDANO_ASSETS`, a
+/// comma-separated list of `policy_id.asset_name_hex:SYMBOL` entries.
+fn known_assets() -> HashMap<(String, String), String> {
+    let mut assets: HashMap<(String, String), String> = VERIFIED_ASSETS
+        .iter()
+        .map(|(policy, name, symbol)| ((policy.to_string(), name.to_string()), symbol.to_string()))
+        .collect();
+
+    for entry in std::env::var("CARDANO_ASSETS")
+        .unwrap_or_default()
+        .split(',')
+    {
+        if entry.trim().is_empty() {
+            continue;
+        }
+
+        match entry.split_once(':') {
+            Some((unit, symbol)) => {
+                let (policy, name) = unit.trim().split_once('.').unwrap_or((unit.trim(), ""));
+                assets.insert(
+                    (policy.to_lowercase(), name.to_lowercase()),
+                    symbol.trim().to_uppercase(),
+                );
+            }
+            None => warn!("Invalid CARDANO_ASSETS entry: {entry}"),
+        }
+    }
+
+    assets
+}
+
+async fn get_symbol_price_usd(symbol: &str) -> Result<Option<f64>, Box<dyn Error + Send + Sync>> {
+    if USD_STABLECOINS.contains(&symbol) {
+        return Ok(Some(1.0));
+    }
+
+    get_price_usd(symbol).await
+}
+
+pub async fn get_cardano_wallet_value(
+    wallet: &str,
+) -> Result<Option<WalletValue>, Box<dyn Error + Send + Sync>> {
+    let client = http::client();
+    let provider = Provider::from_env();
+
+    let holdings = provider.holdings(&client, wallet).await?;
+
+    let ada_price = get_price_usd("ADA")
+        .await?
+        .ok_or("No price found for ADA")?;
+
+    let mut assets = vec![AssetValue::new(
+        "ADA",
+        holdings.lovelace / LOVELACE_PER_ADA,
+        ada_price,
+    )];
+
+    let known = known_assets();
+    let mut unpriced = vec![];
+
+    for token in holdings.tokens.iter().filter(|t| t.quantity > 0.0) {
+        let key = (token.policy_id.clone(), token.asset_name.clone());
+
+        let Some(symbol) = known.get(&key) else {
+            info!(
+                "Skipping unverified Cardano token {}.{} held by {wallet}",
+                token.policy_id, token.asset_name
+            );
+            continue;
+        };
+
+        let decimals = provider.decimals(&client, token).await?;
+        let quantity = token.quantity / 10f64.powi(decimals as i32);
+
+        match get_symbol_price_usd(symbol).await? {
+            Some(price) => assets.push(AssetValue::new(symbol, quantity, price)),
+            None => {
+                warn!("No price found for Cardano token {symbol}");
+                unpriced.push(symbol.clone());
+            }
+        }
+    }
+
+    Ok(Some(
+        WalletValue::from_assets(assets).with_unpriced(unpriced),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn is_valid_address(address: &str, network: Network) -> bool {
+        decode_address(address, network).is_some()
+    }
+
+    // from CIP-19
+    const BASE: &str = "addr1qx2fxv2umyhttkxyxp8x0dlpdt3k6cwng5pxj3jhsydzer3n0d3vllmyqwsx5wktcd8cc3sq835lu7drv2xwl2wywfgse35a3x";
+    const SCRIPT_BASE: &str = "addr1z8phkx6acpnf78fuvxn0mkew3l0fd058hzquvz7w36x4gten0d3vllmyqwsx5wktcd8cc3sq835lu7drv2xwl2wywfgs9yc0hh";
+    const SCRIPT_STAKE_BASE: &str = "addr1yx2fxv2umyhttkxyxp8x0dlpdt3k6cwng5pxj3jhsydzerkr0vd4msrxnuwnccdxlhdjar77j6lg0wypcc9uar5d2shs2z78ve";
+    const ENTERPRISE: &str = "addr1vx2fxv2umyhttkxyxp8x0dlpdt3k6cwng5pxj3jhsydzers66hrl8";
+    const STAKE: &str = "stake1uyehkck0lajq8gr28t9uxnuvgcqrc6070x3k9r8048z8y5gh6ffgw";
+    const SCRIPT_STAKE: &str = "stake178phkx6acpnf78fuvxn0mkew3l0fd058hzquvz7w36x4gtcccycj5";
+    const TESTNET_BASE: &str = "addr_test1qz2fxv2umyhttkxyxp8x0dlpdt3k6cwng5pxj3jhsydzer3n0d3vllmyqwsx5wktcd8cc3sq835lu7drv2xwl2wywfgs68faae";
+    const TESTNET_STAKE: &str = "stake_test1uqehkck0lajq8gr28t9uxnuvgcqrc6070x3k9r8048z8y5gssrtvn";
+
+    #[test]
+    fn accepts_mainnet_addresses() {
+        assert!(BASE.len() > 90);
+
+        for address in [
+            BASE,
+            SCRIPT_BASE,
+            SCRIPT_STAKE_BASE,
+            ENTERPRISE,
+            STAKE,
+            SCRIPT_STAKE,
+        ] {
+            assert!(is_valid_address(address, Network::Mainnet), "{address}");
+        }
+    }
+
+    #[test]
+    fn rejects_testnet_addresses_on_mainnet_and_the_other_way_around() {
+        for address in [TESTNET_BASE, TESTNET_STAKE] {
+            assert!(!is_valid_address(address, Network::Mainnet), "{address}");
+            assert!(is_valid_address(address, Network::Testnet), "{address}");
+        }
+
+        assert!(!is_valid_address(BASE, Network::Testnet));
+    }
+
+    #[test]
+    fn rejects_corrupted_and_foreign_addresses() {
+        let corrupted = BASE.replacen("qx2", "qx3", 1);
+
+        for address in [
+            corrupted.as_str(),
+            "",
+            "addr1",
+            "bc1qar0srrr7xfkvy5l643lydnw9re59gtzzwf5mdq",
+            "1A1zP1eP5QGefi2DMPTfTL5SLmv7DivfNa",
+        ] {
+            assert!(!is_valid_address(address, Network::Mainnet), "{address}");
+        }
+    }
+
+    #[test]
+    fn finds_the_stake_key_of_base_and_stake_addresses() {
+        let key = |address| stake_key(&decode_address(address, Network::Mainnet).unwrap());
+
+        assert_eq!(key(BASE), key(STAKE));
+        assert_eq!(key(SCRIPT_BASE), key(STAKE));
+        assert_eq!(key(SCRIPT_STAKE_BASE), key(SCRIPT_STAKE));
+        assert_ne!(key(STAKE), key(SCRIPT_STAKE));
+        assert_eq!(key(ENTERPRISE), None);
+    }
+}
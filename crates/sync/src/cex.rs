@@ -0,0 +1,145 @@
+use crate::binance::Binance;
+use crate::bybit::Bybit;
+use crate::coinbase::Coinbase;
+use crate::exchange::get_exchange_rate;
+use crate::kraken::Kraken;
+use crate::report::{AssetValue, WalletValue};
+use async_trait::async_trait;
+use std::collections::BTreeMap;
+use std::error::Error;
+use tracing::warn;
+
+pub type ExchangeError = Box<dyn Error + Send + Sync>;
+
+/// Quantity of an asset held on an exchange, under the exchange's own asset
+/// code.
+#[derive(Debug, Clone)]
+pub struct Balance {
+    pub asset: String,
+    pub quantity: f64,
+}
+
+impl Balance {
+    pub fn new(asset: &str, quantity: f64) -> Self {
+        Balance {
+            asset: asset.to_string(),
+            quantity,
+        }
+    }
+}
+
+/// A centralized exchange whose balances are valued as a single wallet.
+///
+/// Adding an exchange only takes implementing this trait and registering it
+/// in [`configured_exchanges`].
+#[async_trait]
+pub trait Exchange: Send + Sync {
+    /// Name used as the source and wallet of the exchange in reports and as
+    /// the payee of its YNAB transactions.
+    fn name(&self) -> &'static str;
+
+    /// Loads a user's credentials for the exchange from the environment,
+    /// returning `None` when it is not configured.
+    fn from_env(user: Option<&str>) -> Option<Self>
+    where
+        Self: Sized;
+
+    /// Balances of the spot and funding accounts.
+    async fn spot_balances(&self) -> Result<Vec<Balance>, ExchangeError>;
+
+    /// Balances locked in earn, savings or staking products.
+    async fn earn_balances(&self) -> Result<Vec<Balance>, ExchangeError> {
+        Ok(vec![])
+    }
+
+    /// Balances of every account the wallet is valued with. Exchanges that
+    /// return all of them at once override this to make a single request.
+    async fn balances(&self) -> Result<Vec<Balance>, ExchangeError> {
+        let mut balances = self.spot_balances().await?;
+        balances.extend(self.earn_balances().await?);
+        Ok(balances)
+    }
+
+    /// Maps an asset code of the exchange to its canonical symbol, e.g. `BTC`.
+    fn normalize_symbol(&self, asset: &str) -> String {
+        asset.to_uppercase()
+    }
+
+    /// Returns the USD price of a canonical symbol.
+    async fn price_usd(&self, symbol: &str) -> Result<f64, ExchangeError>;
+}
+
+/// Returns the exchanges configured in the environment for a user.
+pub fn configured_exchanges(user: Option<&str>) -> Vec<Box<dyn Exchange>> {
+    let mut exchanges: Vec<Box<dyn Exchange>> = vec![];
+
+    if let Some(exchange) = Binance::from_env(user) {
+        exchanges.push(Box::new(exchange));
+    }
+    if let Some(exchange) = Coinbase::from_env(user) {
+        exchanges.push(Box::new(exchange));
+    }
+    if let Some(exchange) = Kraken::from_env(user) {
+        exchanges.push(Box::new(exchange));
+    }
+    if let Some(exchange) = Bybit::from_env(user) {
+        exchanges.push(Box::new(exchange));
+    }
+
+    exchanges
+}
+
+/// Values the balances of an exchange, merged by symbol. Assets
+/// that cannot be priced are left out and listed as unpriced.
+pub async fn get_exchange_wallet_value(
+    exchange: &dyn Exchange,
+) -> Result<WalletValue, ExchangeError> {
+    let balances = exchange.balances().await?;
+
+    let mut quantities: BTreeMap<String, f64> = BTreeMap::new();
+
+    for balance in balances {
+        *quantities
+            .entry(exchange.normalize_symbol(&balance.asset))
+            .or_default() += balance.quantity;
+    }
+
+    let mut assets = vec![];
+    let mut unpriced = vec![];
+
+    for (symbol, quantity) in quantities {
+        if quantity == 0.0 {
+            continue;
+        }
+
+        match get_price_usd(exchange, &symbol).await {
+            Ok(price) => assets.push(AssetValue::new(&symbol, quantity, price)),
+            Err(e) => {
+                warn!(
+                    "No price found for {} on {}: {}",
+                    symbol,
+                    exchange.name(),
+                    e
+                );
+                unpriced.push(symbol);
+            }
+        }
+    }
+
+    Ok(WalletValue::from_assets(assets).with_unpriced(unpriced))
+}
+
+/// Prices a symbol on the exchange, falling back to the fiat exchange rate for
+/// fiat currencies and symbols the exchange does not quote in USD.
+async fn get_price_usd(exchange: &dyn Exchange, symbol: &str) -> Result<f64, ExchangeError> {
+    if symbol == "USD" {
+        return Ok(1.0);
+    }
+
+    match exchange.price_usd(symbol).await {
+        Ok(price) => Ok(price),
+        Err(_) => get_exchange_rate(symbol, "USD")
+            .await
+            .map_err(|e| e.to_string().into()),
+    }
+}
@@ -0,0 +1,95 @@
+use crate::cex::{Balance, Exchange, ExchangeError};
+use crate::http;
+use crate::oauth;
+use async_trait::async_trait;
+use coinbase_api::apis::accounts_api::list_accounts;
+use coinbase_api::apis::configuration::{ApiKey, Configuration};
+use coinbase_api::apis::products_api::get_public_product;
+use tracing::error;
+
+const PAGE_SIZE: i32 = 250;
+
+pub struct Coinbase {
+    config: Configuration,
+    private_key: String,
+}
+
+#[async_trait]
+impl Exchange for Coinbase {
+    fn name(&self) -> &'static str {
+        "Coinbase"
+    }
+
+    fn from_env(user: Option<&str>) -> Option<Self> {
+        let key = oauth::user_var("COINBASE_API_KEY_NAME", user)?;
+
+        let mut config = Configuration {
+            api_key: Some(ApiKey { key }),
+            client: http::client(),
+            user_agent: Some(http::user_agent()),
+            ..Default::default()
+        };
+
+        if let Some(base_path) = std::env::var("COINBASE_API_URL")
+            .ok()
+            .filter(|u| !u.is_empty())
+        {
+            config.base_path = base_path.trim_end_matches('/').to_string();
+        }
+
+        let Some(private_key) = oauth::user_var("COINBASE_API_PRIVATE_KEY", user) else {
+            error!("Coinbase API key set without its private key, skipping Coinbase");
+            return None;
+        };
+
+        // keys copied from the CDP portal into a .env file have escaped newlines
+        let private_key = private_key.replace("\\n", "\n");
+
+        Some(Coinbase {
+            config,
+            private_key,
+        })
+    }
+
+    async fn spot_balances(&self) -> Result<Vec<Balance>, ExchangeError> {
+        let mut balances = vec![];
+        let mut cursor = None;
+
+        loop {
+            let page = list_accounts(
+                &self.config,
+                &self.private_key,
+                Some(PAGE_SIZE),
+                cursor.as_deref(),
+            )
+            .await?;
+
+            for account in page.accounts {
+                let available = account.available_balance.value.parse::<f64>()?;
+                let hold = match &account.hold {
+                    Some(hold) => hold.value.parse::<f64>()?,
+                    None => 0.0,
+                };
+
+                balances.push(Balance::new(&account.currency, available + hold));
+            }
+
+            match page.cursor.filter(|c| page.has_next && !c.is_empty()) {
+                Some(next) => cursor = Some(next),
+                None => break,
+            }
+        }
+
+        Ok(balances)
+    }
+
+    async fn price_usd(&self, symbol: &str) -> Result<f64, ExchangeError> {
+        if symbol == "USDC" {
+            return Ok(1.0);
+        }
+
+        let product = get_public_product(&self.config, &format!("{symbol}-USD")).await?;
+
+        Ok(product.price.parse()?)
+    }
+}
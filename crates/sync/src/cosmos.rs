@@ -0,0 +1,387 @@
+use crate::http;
+use crate::prices::get_price_usd;
+use crate::report::{AssetValue, WalletValue};
+use bech32::primitives::decode::CheckedHrpstring;
+use bech32::Bech32;
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::error::Error;
+use tracing::{info, warn};
+
+const PAGE_SIZE: usize = 200;
+
+/// Chains supported out of the box, as `prefix|lcd|denom|exponent|symbol`.
+const BUILTIN_CHAINS: [&str; 3] = [
+    "cosmos|https://rest.cosmos.directory/cosmoshub|uatom|6|ATOM",
+    "osmo|https://rest.cosmos.directory/osmosis|uosmo|6|OSMO",
+    "celestia|https://rest.cosmos.directory/celestia|utia|6|TIA",
+];
+
+/// IBC assets valued out of the box, as `prefix|path|base_denom|exponent|symbol`.
+/// Anyone can send a token with any base denom over their own channel, so IBC
+/// denoms are only valued when their trace matches a known channel.
+const BUILTIN_IBC_ASSETS: [&str; 5] = [
+    "cosmos|transfer/channel-141|uosmo|6|OSMO",
+    "cosmos|transfer/channel-536|uusdc|6|USDC",
+    "osmo|transfer/channel-0|uatom|6|ATOM",
+    "osmo|transfer/channel-750|uusdc|6|USDC",
+    "osmo|transfer/channel-6994|utia|6|TIA",
+];
+
+/// A chain registry entry: the chain's address prefix, LCD (REST) endpoint and
+/// staking denom.
+#[derive(Debug, Clone)]
+pub struct Chain {
+    pub prefix: String,
+    pub lcd: String,
+    pub denom: String,
+    pub exponent: u32,
+    pub symbol: String,
+}
+
+impl Chain {
+    fn parse(entry: &str) -> Option<Self> {
+        let mut fields = entry.split('|').map(str::trim);
+
+        let chain = Chain {
+            prefix: fields.next()?.to_string(),
+            lcd: fields.next()?.trim_end_matches('/').to_string(),
+            denom: fields.next()?.to_string(),
+            exponent: fields.next()?.parse().ok()?,
+            symbol: fields.next()?.to_uppercase(),
+        };
+
+        fields.next().is_none().then_some(chain)
+    }
+}
+
+/// Returns the built-in chains, overridden and extended by the entries in
+/// `COSMOS_CHAINS`, a comma-separated list of `prefix|lcd|denom|exponent|symbol`.
+pub fn chains() -> Vec<Chain> {
+    let mut chains: BTreeMap<String, Chain> = BTreeMap::new();
+
+    let configured = std::env::var("COSMOS_CHAINS").unwrap_or_default();
+
+    for entry in BUILTIN_CHAINS
+        .into_iter()
+        .chain(configured.split(','))
+        .filter(|e| !e.trim().is_empty())
+    {
+        match Chain::parse(entry) {
+            Some(chain) => {
+                chains.insert(chain.prefix.clone(), chain);
+            }
+            None => warn!("Invalid COSMOS_CHAINS entry: {entry}"),
+        }
+    }
+
+    chains.into_values().collect()
+}
+
+/// An asset received over a known IBC channel of a chain.
+#[derive(Debug, Clone)]
+struct IbcAsset {
+    prefix: String,
+    path: String,
+    base_denom: String,
+    exponent: u32,
+    symbol: String,
+}
+
+impl IbcAsset {
+    fn parse(entry: &str) -> Option<Self> {
+        let mut fields = entry.split('|').map(str::trim);
+
+        let asset = IbcAsset {
+            prefix: fields.next()?.to_string(),
+            path: fields.next()?.trim_matches('/').to_string(),
+            base_denom: fields.next()?.to_string(),
+            exponent: fields.next()?.parse().ok()?,
+            symbol: fields.next()?.to_uppercase(),
+        };
+
+        fields.next().is_none().then_some(asset)
+    }
+}
+
+/// Returns the built-in IBC assets and those in `COSMOS_IBC_ASSETS`, a
+/// comma-separated list of `prefix|path|base_denom|exponent|symbol`.
+fn ibc_assets() -> Vec<IbcAsset> {
+    let configured = std::env::var("COSMOS_IBC_ASSETS").unwrap_or_default();
+
+    BUILTIN_IBC_ASSETS
+        .into_iter()
+        .chain(configured.split(','))
+        .filter(|e| !e.trim().is_empty())
+        .filter_map(|entry| {
+            let asset = IbcAsset::parse(entry);
+            if asset.is_none() {
+                warn!("Invalid COSMOS_IBC_ASSETS entry: {entry}");
+            }
+            asset
+        })
+        .collect()
+}
+
+/// Returns the chain of a bech32 account address, if it is valid and its
+/// prefix belongs to a configured chain.
+pub fn chain_for_address(address: &str, chains: &[Chain]) -> Option<Chain> {
+    let checked = CheckedHrpstring::new::<Bech32>(address).ok()?;
+
+    // 20 byte accounts, or 32 byte module and interchain accounts
+    let length = checked.byte_iter().len();
+    if length != 20 && length != 32 {
+        return None;
+    }
+
+    chains
+        .iter()
+        .find(|c| c.prefix == checked.hrp().as_str())
+        .cloned()
+}
+
+#[derive(Debug, Deserialize)]
+struct Coin {
+    denom: String,
+    amount: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct Pagination {
+    next_key: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct BalancesResponse {
+    balances: Vec<Coin>,
+    pagination: Option<Pagination>,
+}
+
+#[derive(Debug, Deserialize)]
+struct DelegationResponse {
+    balance: Coin,
+}
+
+#[derive(Debug, Deserialize)]
+struct DelegationsResponse {
+    delegation_responses: Vec<DelegationResponse>,
+    pagination: Option<Pagination>,
+}
+
+#[derive(Debug, Deserialize)]
+struct UnbondingEntry {
+    balance: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct UnbondingDelegation {
+    entries: Vec<UnbondingEntry>,
+}
+
+#[derive(Debug, Deserialize)]
+struct UnbondingResponse {
+    unbonding_responses: Vec<UnbondingDelegation>,
+    pagination: Option<Pagination>,
+}
+
+#[derive(Debug, Deserialize)]
+struct RewardsResponse {
+    /// Decimal amounts, e.g. `"1234.567000000000000000"`.
+    #[serde(default)]
+    total: Vec<Coin>,
+}
+
+#[derive(Debug, Deserialize)]
+struct DenomTrace {
+    /// The ports and channels the token came over, e.g. `transfer/channel-0`.
+    path: String,
+    base_denom: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct DenomTraceResponse {
+    denom_trace: DenomTrace,
+}
+
+async fn get<T: for<'de> Deserialize<'de>>(
+    request: reqwest::RequestBuilder,
+) -> Result<T, Box<dyn Error + Send + Sync>> {
+    Ok(request
+        .send()
+        .await?
+        .error_for_status()?
+        .json::<T>()
+        .await?)
+}
+
+/// Requests a page of a paginated endpoint, starting at `key`.
+async fn get_page<T: for<'de> Deserialize<'de>>(
+    client: &reqwest::Client,
+    url: &str,
+    key: Option<&str>,
+) -> Result<T, Box<dyn Error + Send + Sync>> {
+    let mut request = client
+        .get(url)
+        .query(&[("pagination.limit", PAGE_SIZE.to_string())]);
+
+    if let Some(key) = key {
+        request = request.query(&[("pagination.key", key)]);
+    }
+
+    get(request).await
+}
+
+/// Returns the amounts held by an address by denom, in base units: its bank
+/// balances, delegations, unbonding entries and pending rewards.
+async fn get_amounts(
+    client: &reqwest::Client,
+    chain: &Chain,
+    address: &str,
+) -> Result<BTreeMap<String, f64>, Box<dyn Error + Send + Sync>> {
+    let lcd = &chain.lcd;
+    let mut amounts: BTreeMap<String, f64> = BTreeMap::new();
+
+    let mut key = None;
+    loop {
+        let page: BalancesResponse = get_page(
+            client,
+            &format!("{lcd}/cosmos/bank/v1beta1/balances/{address}"),
+            key.as_deref(),
+        )
+        .await?;
+
+        for coin in page.balances {
+            *amounts.entry(coin.denom).or_default() += coin.amount.parse::<f64>()?;
+        }
+
+        key = page.pagination.and_then(|p| p.next_key);
+        if key.is_none() {
+            break;
+        }
+    }
+
+    let mut key = None;
+    loop {
+        let page: DelegationsResponse = get_page(
+            client,
+            &format!("{lcd}/cosmos/staking/v1beta1/delegations/{address}"),
+            key.as_deref(),
+        )
+        .await?;
+
+        for delegation in page.delegation_responses {
+            *amounts.entry(delegation.balance.denom).or_default() +=
+                delegation.balance.amount.parse::<f64>()?;
+        }
+
+        key = page.pagination.and_then(|p| p.next_key);
+        if key.is_none() {
+            break;
+        }
+    }
+
+    let mut key = None;
+    loop {
+        let page: UnbondingResponse = get_page(
+            client,
+            &format!("{lcd}/cosmos/staking/v1beta1/delegators/{address}/unbonding_delegations"),
+            key.as_deref(),
+        )
+        .await?;
+
+        // unbonding entries are always in the staking denom
+        for entry in page.unbonding_responses.iter().flat_map(|u| &u.entries) {
+            *amounts.entry(chain.denom.clone()).or_default() += entry.balance.parse::<f64>()?;
+        }
+
+        key = page.pagination.and_then(|p| p.next_key);
+        if key.is_none() {
+            break;
+        }
+    }
+
+    let rewards: RewardsResponse = get(client.get(format!(
+        "{lcd}/cosmos/distribution/v1beta1/delegators/{address}/rewards"
+    )))
+    .await?;
+
+    for coin in rewards.total {
+        *amounts.entry(coin.denom).or_default() += coin.amount.parse::<f64>()?;
+    }
+
+    Ok(amounts)
+}
+
+/// Resolves a denom to its symbol and exponent. IBC denoms are traced back to
+/// their channel and base denom on the chain's `denom_traces` endpoint, and
+/// only resolved when that trace is a known IBC asset of the chain.
+async fn resolve_denom(
+    client: &reqwest::Client,
+    chain: &Chain,
+    ibc_assets: &[IbcAsset],
+    denom: &str,
+) -> Result<Option<(String, u32)>, Box<dyn Error + Send + Sync>> {
+    let Some(hash) = denom.strip_prefix("ibc/") else {
+        return Ok((denom == chain.denom).then(|| (chain.symbol.clone(), chain.exponent)));
+    };
+
+    let trace: DenomTraceResponse = get(client.get(format!(
+        "{}/ibc/apps/transfer/v1/denom_traces/{hash}",
+        chain.lcd
+    )))
+    .await?;
+    let trace = trace.denom_trace;
+
+    Ok(ibc_assets
+        .iter()
+        .find(|a| {
+            a.prefix == chain.prefix && a.path == trace.path && a.base_denom == trace.base_denom
+        })
+        .map(|a| (a.symbol.clone(), a.exponent)))
+}
+
+pub async fn get_cosmos_wallet_value(
+    wallet: &str,
+) -> Result<Option<WalletValue>, Box<dyn Error + Send + Sync>> {
+    let chains = chains();
+    let chain = chain_for_address(wallet, &chains).ok_or("Unknown Cosmos chain")?;
+    let ibc_assets = ibc_assets();
+    let client = http::client();
+
+    let amounts = get_amounts(&client, &chain, wallet).await?;
+
+    // the same asset can be held natively and over IBC
+    let mut quantities: BTreeMap<String, f64> = BTreeMap::new();
+
+    for (denom, amount) in amounts {
+        if amount == 0.0 {
+            continue;
+        }
+
+        // spam IBC tokens often have no trace, which must not fail the wallet
+        match resolve_denom(&client, &chain, &ibc_assets, &denom).await {
+            Ok(Some((symbol, exponent))) => {
+                *quantities.entry(symbol).or_default() += amount / 10f64.powi(exponent as i32)
+            }
+            Ok(None) => info!("Skipping unknown or unverified denom {denom} held by {wallet}"),
+            Err(e) => warn!("Skipping denom {denom} held by {wallet}, failed to resolve it: {e}"),
+        }
+    }
+
+    let mut assets = vec![];
+    let mut unpriced = vec![];
+
+    for (symbol, quantity) in quantities {
+        match get_price_usd(&symbol).await? {
+            Some(price) => assets.push(AssetValue::new(&symbol, quantity, price)),
+            None => {
+                warn!("No price found for {symbol}");
+                unpriced.push(symbol);
+            }
+        }
+    }
+
+    Ok(Some(
+        WalletValue::from_assets(assets).with_unpriced(unpriced),
+    ))
+}
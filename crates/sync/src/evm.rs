@@ -1,3 +1,4 @@
+use crate::report::WalletValue;
 use std::error::Error;
 use std::time::Duration;
 use tokio::time::sleep;
@@ -5,7 +6,7 @@ use tracing::info;
 
 const SECONDS_TO_SLEEP: u64 = 5;
 
-pub async fn get_evm_wallet_net_worth(wallet: &str) -> Result<Option<f64>, Box<dyn Error>> {
+pub async fn get_evm_wallet_net_worth(wallet: &str) -> Result<Option<WalletValue>, Box<dyn Error>> {
     #[cfg(feature = "headless")]
     return get_net_worth_from_debank_with_headless_chrome(wallet).await;
 
@@ -16,7 +17,7 @@ pub async fn get_evm_wallet_net_worth(wallet: &str) -> Result<Option<f64>, Box<d
 #[cfg(feature = "headless")]
 pub async fn get_net_worth_from_debank_with_headless_chrome(
     wallet: &str,
-) -> Result<Option<f64>, Box<dyn Error>> {
+) -> Result<Option<WalletValue>, Box<dyn Error>> {
     use headless_chrome::{Browser, LaunchOptions};
     let browser = Browser::new(
         LaunchOptions::default_builder()
@@ -49,13 +50,13 @@ pub async fn get_net_worth_from_debank_with_headless_chrome(
             .ok()
     });
 
-    Ok(first_line)
+    Ok(first_line.map(WalletValue::from_total))
 }
 
 #[cfg(feature = "docker")]
 pub async fn get_net_worth_from_debank_with_fantoccini(
     wallet: &str,
-) -> Result<Option<f64>, Box<dyn Error>> {
+) -> Result<Option<WalletValue>, Box<dyn Error>> {
     use fantoccini::wd::Capabilities;
     use fantoccini::{ClientBuilder, Locator};
 
@@ -97,5 +98,5 @@ pub async fn get_net_worth_from_debank_with_fantoccini(
 
     c.close().await?;
 
-    Ok(first_line)
+    Ok(first_line.map(WalletValue::from_total))
 }
@@ -1,6 +1,8 @@
+use crate::http;
 use chrono::NaiveDate;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::env;
 use std::error::Error;
 use tracing::info;
 
@@ -11,19 +13,26 @@ struct ExchangeRateResponse {
     rates: HashMap<String, f64>,
 }
 
-const FRANKFURTER_URI: &str = "https://api.frankfurter.app/latest";
+const FRANKFURTER_API_URL: &str = "https://api.frankfurter.app";
 
+/// Returns the latest rate from the Frankfurter API at `FRANKFURTER_API_URL`.
 pub async fn get_exchange_rate(base: &str, to: &str) -> Result<f64, Box<dyn Error>> {
     if base == "USD" {
         return Ok(1.0);
     }
 
-    let response = reqwest::Client::new()
-        .get(FRANKFURTER_URI)
+    let url = env::var("FRANKFURTER_API_URL")
+        .ok()
+        .filter(|u| !u.is_empty())
+        .unwrap_or_else(|| FRANKFURTER_API_URL.to_string());
+
+    let response = http::client()
+        .get(format!("{}/latest", url.trim_end_matches('/')))
         .query(&[("base", &base)])
         .query(&[("symbols", &to)])
         .send()
-        .await?;
+        .await?
+        .error_for_status()?;
 
     let response = response.json::<ExchangeRateResponse>().await?;
 
@@ -32,7 +41,7 @@ pub async fn get_exchange_rate(base: &str, to: &str) -> Result<f64, Box<dyn Erro
     let rate = response
         .rates
         .get(to)
-        .expect(&format!("No rate found for {to}"));
+        .ok_or_else(|| format!("No rate found for {to}"))?;
 
-    Ok(rate.clone())
+    Ok(*rate)
 }
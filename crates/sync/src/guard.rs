@@ -0,0 +1,143 @@
+use std::env;
+
+const DEFAULT_ZERO_VALUE_CONFIRMATIONS: usize = 2;
+
+/// What to do with a value that fails the sanity checks.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GuardMode {
+    /// Record the value in the run report without touching YNAB.
+    Quarantine,
+    /// Record the value in the run report and write it to YNAB anyway.
+    Warn,
+}
+
+/// Sanity checks applied to a wallet's new balance before it is written to
+/// YNAB.
+#[derive(Debug, Clone)]
+pub struct Guard {
+    pub mode: GuardMode,
+    /// Largest change allowed against the balance before today, in percent.
+    pub max_daily_change_percent: Option<f64>,
+    /// Runs in a row a wallet must be valued at zero before a zero balance is
+    /// written.
+    pub zero_value_confirmations: usize,
+}
+
+impl Guard {
+    pub fn from_env() -> Self {
+        Guard {
+            mode: match env::var("GUARD_MODE").as_deref() {
+                Ok("warn") => GuardMode::Warn,
+                _ => GuardMode::Quarantine,
+            },
+            max_daily_change_percent: env::var("MAX_DAILY_CHANGE_PERCENT")
+                .ok()
+                .and_then(|p| p.parse().ok()),
+            zero_value_confirmations: env::var("ZERO_VALUE_CONFIRMATIONS")
+                .ok()
+                .and_then(|n| n.parse().ok())
+                .unwrap_or(DEFAULT_ZERO_VALUE_CONFIRMATIONS),
+        }
+    }
+
+    /// Returns why a new balance looks suspicious, if it does. Balances are in
+    /// milliunits; `zero_runs` is the number of runs in a row, including this
+    /// one, that valued the wallet at zero.
+    pub fn check(&self, previous: i64, new: i64, zero_runs: usize) -> Option<String> {
+        if previous == 0 {
+            return None;
+        }
+
+        if new == 0 && zero_runs < self.zero_value_confirmations {
+            return Some(format!(
+                "zero value reported {zero_runs} time(s), {} needed to confirm",
+                self.zero_value_confirmations
+            ));
+        }
+
+        let change = (new - previous) as f64 / previous.abs() as f64 * 100.0;
+
+        match self.max_daily_change_percent {
+            Some(max) if new != 0 && change.abs() > max => Some(format!(
+                "value changed by {change:+.2}% since yesterday, more than the allowed {max}%"
+            )),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn guard(max_daily_change_percent: Option<f64>) -> Guard {
+        Guard {
+            mode: GuardMode::Quarantine,
+            max_daily_change_percent,
+            zero_value_confirmations: 2,
+        }
+    }
+
+    #[test]
+    fn accepts_any_value_of_a_wallet_without_balance() {
+        let guard = guard(Some(10.0));
+
+        assert_eq!(guard.check(0, 1_000_000, 0), None);
+        assert_eq!(guard.check(0, -1_000_000, 0), None);
+        assert_eq!(guard.check(0, 0, 1), None);
+    }
+
+    #[test]
+    fn writes_a_zero_value_once_confirmed() {
+        let guard = guard(None);
+
+        assert_eq!(
+            guard.check(5_000, 0, 1).as_deref(),
+            Some("zero value reported 1 time(s), 2 needed to confirm")
+        );
+        assert_eq!(guard.check(5_000, 0, 2), None);
+        assert_eq!(guard.check(5_000, 0, 3), None);
+    }
+
+    #[test]
+    fn confirms_a_zero_value_regardless_of_the_change_threshold() {
+        assert_eq!(guard(Some(10.0)).check(5_000, 0, 2), None);
+    }
+
+    #[test]
+    fn flags_changes_beyond_the_threshold() {
+        let guard = guard(Some(10.0));
+
+        assert_eq!(guard.check(10_000, 11_000, 0), None);
+        assert_eq!(guard.check(10_000, 9_000, 0), None);
+        assert_eq!(
+            guard.check(10_000, 11_001, 0).as_deref(),
+            Some("value changed by +10.01% since yesterday, more than the allowed 10%")
+        );
+        assert_eq!(
+            guard.check(10_000, 8_000, 0).as_deref(),
+            Some("value changed by -20.00% since yesterday, more than the allowed 10%")
+        );
+    }
+
+    #[test]
+    fn accepts_any_change_without_a_threshold() {
+        assert_eq!(guard(None).check(10_000, 1_000_000, 0), None);
+        assert_eq!(guard(None).check(10_000, -10_000, 0), None);
+    }
+
+    #[test]
+    fn measures_sign_flips_against_the_absolute_previous_balance() {
+        let guard = guard(Some(150.0));
+
+        assert_eq!(
+            guard.check(10_000, -10_000, 0).as_deref(),
+            Some("value changed by -200.00% since yesterday, more than the allowed 150%")
+        );
+        assert_eq!(
+            guard.check(-10_000, 10_000, 0).as_deref(),
+            Some("value changed by +200.00% since yesterday, more than the allowed 150%")
+        );
+        assert_eq!(guard.check(-10_000, -20_000, 0), None);
+    }
+}
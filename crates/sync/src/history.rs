@@ -0,0 +1,457 @@
+use crate::oauth;
+use crate::report::RunReport;
+use chrono::{DateTime, Utc};
+use rusqlite::{params, Connection, OptionalExtension};
+use std::collections::HashMap;
+use std::env;
+
+const DEFAULT_HISTORY_DB: &str = "history.sqlite";
+
+/// Local SQLite store of every run's wallet and asset valuations.
+pub struct History {
+    conn: Connection,
+}
+
+/// The total value of the portfolio at the time of a run, in the budget currency.
+#[derive(Debug, Clone)]
+pub struct PortfolioTotal {
+    pub at: DateTime<Utc>,
+    pub currency: Option<String>,
+    pub value: f64,
+}
+
+/// A wallet's value in a run, `None` when it failed or was quarantined.
+struct WalletRow {
+    run_id: i64,
+    at: DateTime<Utc>,
+    currency: Option<String>,
+    wallet: (String, String),
+    value: Option<f64>,
+}
+
+/// The value of a wallet or asset as recorded in the latest run, in the budget
+/// currency.
+#[derive(Debug, Clone)]
+pub struct Holding {
+    pub source: String,
+    pub name: String,
+    pub value: f64,
+}
+
+impl History {
+    /// Opens the database at `HISTORY_DB`, or a user's own copy of it,
+    /// creating it if needed.
+    pub fn open(user: Option<&str>) -> rusqlite::Result<Self> {
+        let path = env::var("HISTORY_DB")
+            .ok()
+            .filter(|p| !p.is_empty())
+            .unwrap_or_else(|| DEFAULT_HISTORY_DB.to_string());
+        let path = oauth::user_path(&path, user);
+
+        Self::with_connection(Connection::open(path)?)
+    }
+
+    /// Creates the schema in a database if needed and brings it up to date.
+    pub(crate) fn with_connection(conn: Connection) -> rusqlite::Result<Self> {
+        conn.execute_batch(
+            "CREATE TABLE IF NOT EXISTS runs (
+                id INTEGER PRIMARY KEY,
+                started_at TEXT NOT NULL,
+                currency TEXT,
+                fx_rate REAL
+            );
+            CREATE TABLE IF NOT EXISTS wallet_values (
+                run_id INTEGER NOT NULL REFERENCES runs(id),
+                source TEXT NOT NULL,
+                wallet TEXT NOT NULL,
+                value_usd REAL,
+                value_fiat REAL,
+                error TEXT
+            );
+            CREATE TABLE IF NOT EXISTS asset_values (
+                run_id INTEGER NOT NULL REFERENCES runs(id),
+                source TEXT NOT NULL,
+                wallet TEXT NOT NULL,
+                symbol TEXT NOT NULL,
+                quantity REAL NOT NULL,
+                price_usd REAL NOT NULL,
+                value_usd REAL NOT NULL
+            );
+            CREATE INDEX IF NOT EXISTS wallet_values_run_id ON wallet_values(run_id);
+            CREATE INDEX IF NOT EXISTS asset_values_run_id ON asset_values(run_id);",
+        )?;
+
+        migrate(&conn)?;
+
+        Ok(History { conn })
+    }
+
+    pub fn record(&mut self, report: &RunReport) -> rusqlite::Result<()> {
+        let tx = self.conn.transaction()?;
+
+        tx.execute(
+            "INSERT INTO runs (started_at, currency, fx_rate, succeeded) VALUES (?1, ?2, ?3, ?4)",
+            params![
+                report.started_at,
+                report.currency,
+                report.fx_rate,
+                report.errors.is_empty()
+            ],
+        )?;
+
+        let run_id = tx.last_insert_rowid();
+
+        for wallet in &report.wallets {
+            tx.execute(
+                "INSERT INTO wallet_values (run_id, source, wallet, value_usd, value_fiat, error, quarantined)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)",
+                params![
+                    run_id,
+                    wallet.source,
+                    wallet.wallet,
+                    wallet.value_usd,
+                    wallet.value_fiat,
+                    wallet.error,
+                    wallet.quarantined
+                ],
+            )?;
+
+            for asset in &wallet.assets {
+                tx.execute(
+                    "INSERT INTO asset_values (run_id, source, wallet, symbol, quantity, price_usd, value_usd)
+                     VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)",
+                    params![
+                        run_id,
+                        wallet.source,
+                        wallet.wallet,
+                        asset.symbol,
+                        asset.quantity,
+                        asset.price_usd,
+                        asset.value_usd
+                    ],
+                )?;
+            }
+        }
+
+        tx.commit()
+    }
+
+    /// Returns the portfolio total of every run that valued at least one
+    /// wallet, oldest first. A wallet that failed or was quarantined in a run
+    /// counts with its last known value, so it does not show as a drop.
+    pub fn portfolio_totals(&self) -> rusqlite::Result<Vec<PortfolioTotal>> {
+        let mut stmt = self.conn.prepare(
+            "SELECT r.id, r.started_at, r.currency, w.source, w.wallet,
+                CASE WHEN w.quarantined THEN NULL ELSE w.value_fiat END
+             FROM runs r JOIN wallet_values w ON w.run_id = r.id
+             ORDER BY r.started_at, r.id",
+        )?;
+
+        let rows = stmt
+            .query_map([], |row| {
+                Ok(WalletRow {
+                    run_id: row.get(0)?,
+                    at: row.get(1)?,
+                    currency: row.get(2)?,
+                    wallet: (row.get(3)?, row.get(4)?),
+                    value: row.get(5)?,
+                })
+            })?
+            .collect::<rusqlite::Result<Vec<_>>>()?;
+
+        let mut last_values: HashMap<&(String, String), f64> = HashMap::new();
+        let mut totals = vec![];
+
+        for run in rows.chunk_by(|a, b| a.run_id == b.run_id) {
+            let mut valued = false;
+            let mut total = 0.0;
+
+            for row in run {
+                if let Some(value) = row.value {
+                    last_values.insert(&row.wallet, value);
+                    valued = true;
+                }
+                total += last_values.get(&row.wallet).copied().unwrap_or_default();
+            }
+
+            if valued {
+                totals.push(PortfolioTotal {
+                    at: run[0].at,
+                    currency: run[0].currency.clone(),
+                    value: total,
+                });
+            }
+        }
+
+        Ok(totals)
+    }
+
+    /// Returns the value of every wallet in the latest run that valued one.
+    pub fn latest_wallets(&self) -> rusqlite::Result<Vec<Holding>> {
+        let mut stmt = self.conn.prepare(
+            "SELECT source, wallet, value_fiat FROM wallet_values
+             WHERE value_fiat IS NOT NULL AND NOT quarantined AND run_id = (
+                SELECT MAX(run_id) FROM wallet_values
+                WHERE value_fiat IS NOT NULL AND NOT quarantined
+             )",
+        )?;
+
+        let holdings = stmt
+            .query_map([], |row| {
+                Ok(Holding {
+                    source: row.get(0)?,
+                    name: row.get(1)?,
+                    value: row.get(2)?,
+                })
+            })?
+            .collect();
+
+        holdings
+    }
+
+    /// Returns the value of every asset in the latest run that valued a wallet,
+    /// converted with that run's exchange rate.
+    pub fn latest_assets(&self) -> rusqlite::Result<Vec<Holding>> {
+        let mut stmt = self.conn.prepare(
+            "SELECT a.source, a.symbol, a.value_usd / r.fx_rate
+             FROM asset_values a
+             JOIN runs r ON r.id = a.run_id
+             JOIN wallet_values w
+                ON w.run_id = a.run_id AND w.source = a.source AND w.wallet = a.wallet
+             WHERE NOT w.quarantined AND a.run_id = (
+                SELECT MAX(run_id) FROM wallet_values
+                WHERE value_fiat IS NOT NULL AND NOT quarantined
+             )",
+        )?;
+
+        let holdings = stmt
+            .query_map([], |row| {
+                Ok(Holding {
+                    source: row.get(0)?,
+                    name: row.get(1)?,
+                    value: row.get(2)?,
+                })
+            })?
+            .collect();
+
+        holdings
+    }
+
+    /// Returns the start time of the latest run that completed without errors.
+    pub fn last_successful_run(&self) -> rusqlite::Result<Option<DateTime<Utc>>> {
+        self.conn.query_row(
+            "SELECT MAX(started_at) FROM runs WHERE succeeded = 1",
+            [],
+            |row| row.get(0),
+        )
+    }
+
+    /// Returns the start time of the latest run that valued each wallet.
+    pub fn last_wallet_successes(&self) -> rusqlite::Result<Vec<(String, String, DateTime<Utc>)>> {
+        let mut stmt = self.conn.prepare(
+            "SELECT w.source, w.wallet, MAX(r.started_at)
+             FROM wallet_values w JOIN runs r ON r.id = w.run_id
+             WHERE w.value_usd IS NOT NULL
+             GROUP BY w.source, w.wallet",
+        )?;
+
+        let successes = stmt
+            .query_map([], |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?)))?
+            .collect();
+
+        successes
+    }
+
+    /// Returns how many of the latest recorded runs in a row failed to value a
+    /// wallet.
+    pub fn consecutive_failures(&self, source: &str, wallet: &str) -> rusqlite::Result<usize> {
+        let mut stmt = self.conn.prepare(
+            "SELECT value_usd IS NULL FROM wallet_values
+             WHERE source = ?1 AND wallet = ?2
+             ORDER BY run_id DESC",
+        )?;
+
+        let mut rows = stmt.query(params![source, wallet])?;
+        let mut failures = 0;
+
+        while let Some(row) = rows.next()? {
+            if !row.get::<_, bool>(0)? {
+                break;
+            }
+            failures += 1;
+        }
+
+        Ok(failures)
+    }
+
+    /// Returns how many of the latest recorded runs in a row valued a wallet at
+    /// zero.
+    pub fn consecutive_zero_values(&self, source: &str, wallet: &str) -> rusqlite::Result<usize> {
+        let mut stmt = self.conn.prepare(
+            "SELECT value_usd = 0 FROM wallet_values
+             WHERE source = ?1 AND wallet = ?2
+             ORDER BY run_id DESC",
+        )?;
+
+        let mut rows = stmt.query(params![source, wallet])?;
+        let mut zeros = 0;
+
+        while let Some(row) = rows.next()? {
+            if !row.get::<_, Option<bool>>(0)?.unwrap_or_default() {
+                break;
+            }
+            zeros += 1;
+        }
+
+        Ok(zeros)
+    }
+
+    /// Returns the latest recorded value of a wallet in the budget currency
+    /// that was not quarantined.
+    pub fn last_value(&self, source: &str, wallet: &str) -> rusqlite::Result<Option<f64>> {
+        self.conn
+            .query_row(
+                "SELECT value_fiat FROM wallet_values
+                 WHERE source = ?1 AND wallet = ?2 AND value_fiat IS NOT NULL AND NOT quarantined
+                 ORDER BY run_id DESC LIMIT 1",
+                params![source, wallet],
+                |row| row.get(0),
+            )
+            .optional()
+    }
+}
+
+/// Applies the schema changes made after the first release, tracked with
+/// `PRAGMA user_version`.
+fn migrate(conn: &Connection) -> rusqlite::Result<()> {
+    let version: i64 = conn.query_row("PRAGMA user_version", [], |row| row.get(0))?;
+
+    if version < 1 {
+        conn.execute_batch(
+            "ALTER TABLE runs ADD COLUMN succeeded INTEGER;
+            PRAGMA user_version = 1;",
+        )?;
+    }
+
+    if version < 2 {
+        conn.execute_batch(
+            "ALTER TABLE wallet_values ADD COLUMN quarantined INTEGER NOT NULL DEFAULT 0;
+            PRAGMA user_version = 2;",
+        )?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::report::WalletReport;
+    use chrono::{Duration, TimeZone};
+
+    fn history() -> History {
+        History::with_connection(Connection::open_in_memory().unwrap()).unwrap()
+    }
+
+    /// A run `day` days after the first, valuing the given wallets. A `None`
+    /// value is a failed wallet, a negative one a quarantined wallet.
+    fn run(day: i64, wallets: &[(&str, Option<f64>)]) -> RunReport {
+        let mut report = RunReport::new(None);
+        report.started_at =
+            Utc.with_ymd_and_hms(2026, 1, 1, 0, 0, 0).unwrap() + Duration::days(day);
+        report.currency = Some("EUR".to_string());
+        report.fx_rate = Some(1.0);
+        report.wallets = wallets
+            .iter()
+            .map(|(name, value)| {
+                let mut wallet = WalletReport::new("Manual", name);
+                wallet.value_usd = value.map(f64::abs);
+                wallet.value_fiat = value.map(f64::abs);
+                wallet.quarantined = value.is_some_and(|v| v < 0.0);
+                if value.is_none() {
+                    wallet.error = Some("failed".to_string());
+                }
+                wallet
+            })
+            .collect();
+        report
+    }
+
+    fn totals(history: &History) -> Vec<f64> {
+        history
+            .portfolio_totals()
+            .unwrap()
+            .iter()
+            .map(|t| t.value)
+            .collect()
+    }
+
+    #[test]
+    fn migrates_a_database_of_the_first_release() {
+        let conn = Connection::open_in_memory().unwrap();
+        conn.execute_batch(
+            "CREATE TABLE runs (id INTEGER PRIMARY KEY, started_at TEXT NOT NULL, currency TEXT, fx_rate REAL);
+            CREATE TABLE wallet_values (run_id INTEGER NOT NULL, source TEXT NOT NULL, wallet TEXT NOT NULL,
+                value_usd REAL, value_fiat REAL, error TEXT);
+            INSERT INTO runs VALUES (1, '2026-01-01T00:00:00Z', 'EUR', 1.0);
+            INSERT INTO wallet_values VALUES (1, 'Manual', 'Savings', 100.0, 100.0, NULL);",
+        )
+        .unwrap();
+
+        let history = History::with_connection(conn).unwrap();
+
+        let version: i64 = history
+            .conn
+            .query_row("PRAGMA user_version", [], |row| row.get(0))
+            .unwrap();
+        assert_eq!(version, 2);
+        assert_eq!(
+            history.last_value("Manual", "Savings").unwrap(),
+            Some(100.0)
+        );
+        assert_eq!(history.last_successful_run().unwrap(), None);
+
+        // migrating again is a no-op
+        let history = History::with_connection(history.conn).unwrap();
+        assert_eq!(totals(&history), vec![100.0]);
+    }
+
+    #[test]
+    fn carries_failed_and_quarantined_wallets_at_their_last_value() {
+        let mut history = history();
+
+        history
+            .record(&run(
+                0,
+                &[("Savings", Some(100.0)), ("Exchange", Some(50.0))],
+            ))
+            .unwrap();
+        history
+            .record(&run(1, &[("Savings", Some(110.0)), ("Exchange", None)]))
+            .unwrap();
+        history
+            .record(&run(
+                2,
+                &[("Savings", Some(-1.0)), ("Exchange", Some(60.0))],
+            ))
+            .unwrap();
+        // a run that valued nothing has no total
+        history
+            .record(&run(3, &[("Savings", None), ("Exchange", None)]))
+            .unwrap();
+        // a wallet no longer configured drops out
+        history
+            .record(&run(4, &[("Savings", Some(120.0))]))
+            .unwrap();
+
+        assert_eq!(totals(&history), vec![150.0, 160.0, 170.0, 120.0]);
+        assert_eq!(
+            history.last_value("Manual", "Savings").unwrap(),
+            Some(120.0)
+        );
+        assert_eq!(
+            history.consecutive_failures("Manual", "Exchange").unwrap(),
+            1
+        );
+    }
+}
@@ -0,0 +1,91 @@
+use std::env;
+use std::error::Error;
+use std::sync::OnceLock;
+use std::time::Duration;
+use tracing::warn;
+
+const DEFAULT_USER_AGENT: &str = concat!("ynab-crypto-sync/", env!("CARGO_PKG_VERSION"));
+const DEFAULT_CONNECT_TIMEOUT_SECONDS: u64 = 10;
+const DEFAULT_READ_TIMEOUT_SECONDS: u64 = 30;
+
+static CLIENT: OnceLock<reqwest::Client> = OnceLock::new();
+
+fn var(name: &str) -> Option<String> {
+    env::var(name).ok().filter(|v| !v.is_empty())
+}
+
+fn seconds(name: &str, default: u64) -> Result<Duration, Box<dyn Error + Send + Sync>> {
+    let seconds = match var(name) {
+        Some(value) => value
+            .parse()
+            .map_err(|_| format!("{name} must be a number of seconds, got `{value}`"))?,
+        None => default,
+    };
+
+    Ok(Duration::from_secs(seconds))
+}
+
+/// The user agent of every request, `HTTP_USER_AGENT` or the project's own.
+pub fn user_agent() -> String {
+    var("HTTP_USER_AGENT").unwrap_or_else(|| DEFAULT_USER_AGENT.to_string())
+}
+
+/// Builds a client with the timeouts in `HTTP_CONNECT_TIMEOUT_SECONDS` and
+/// `HTTP_READ_TIMEOUT_SECONDS`, the proxy in `HTTP_PROXY_URL` and the extra
+/// root certificates in the PEM file at `HTTP_CA_CERTS`.
+fn build() -> Result<reqwest::Client, Box<dyn Error + Send + Sync>> {
+    let mut builder = reqwest::Client::builder()
+        .user_agent(user_agent())
+        .connect_timeout(seconds(
+            "HTTP_CONNECT_TIMEOUT_SECONDS",
+            DEFAULT_CONNECT_TIMEOUT_SECONDS,
+        )?)
+        .read_timeout(seconds(
+            "HTTP_READ_TIMEOUT_SECONDS",
+            DEFAULT_READ_TIMEOUT_SECONDS,
+        )?);
+
+    // http://, https://, socks5:// or socks5h:// to resolve names through the
+    // proxy, e.g. Tor's socks5h://127.0.0.1:9050
+    if let Some(proxy) = var("HTTP_PROXY_URL") {
+        builder = builder.proxy(
+            reqwest::Proxy::all(&proxy).map_err(|e| format!("Invalid HTTP_PROXY_URL: {e}"))?,
+        );
+    }
+
+    if let Some(path) = var("HTTP_CA_CERTS") {
+        let pem = std::fs::read(&path).map_err(|e| format!("Cannot read {path}: {e}"))?;
+        let certificates = reqwest::Certificate::from_pem_bundle(&pem)
+            .map_err(|e| format!("Invalid certificates in {path}: {e}"))?;
+
+        if certificates.is_empty() {
+            return Err(format!("No certificates found in {path}").into());
+        }
+
+        for certificate in certificates {
+            builder = builder.add_root_certificate(certificate);
+        }
+    }
+
+    Ok(builder.build()?)
+}
+
+/// Builds the shared client, failing on invalid HTTP settings.
+pub fn init() -> Result<(), Box<dyn Error + Send + Sync>> {
+    let client = build()?;
+    let _ = CLIENT.set(client);
+    Ok(())
+}
+
+/// The client every source and API client sends its requests with. Clones
+/// share its connection pool.
+pub fn client() -> reqwest::Client {
+    CLIENT
+        .get_or_init(|| {
+            build().unwrap_or_else(|e| {
+                warn!("Invalid HTTP settings, using the defaults: {e}");
+                reqwest::Client::new()
+            })
+        })
+        .clone()
+}
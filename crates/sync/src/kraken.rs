@@ -0,0 +1,130 @@
+use crate::cex::{Balance, Exchange, ExchangeError};
+use crate::http;
+use crate::oauth;
+use async_trait::async_trait;
+use kraken_api::apis::account_data_api::get_extended_balance;
+use kraken_api::apis::configuration::{ApiKey, Configuration};
+use kraken_api::apis::market_data_api::get_ticker_information;
+use tracing::error;
+
+/// Kraken's legacy asset codes and the symbols they stand for.
+const LEGACY_CODES: [(&str, &str); 19] = [
+    ("XXBT", "BTC"),
+    ("XBT", "BTC"),
+    ("XXDG", "DOGE"),
+    ("XDG", "DOGE"),
+    ("XETH", "ETH"),
+    ("ETH2", "ETH"),
+    ("XETC", "ETC"),
+    ("XLTC", "LTC"),
+    ("XMLN", "MLN"),
+    ("XREP", "REP"),
+    ("XXLM", "XLM"),
+    ("XXMR", "XMR"),
+    ("XXRP", "XRP"),
+    ("XZEC", "ZEC"),
+    ("ZUSD", "USD"),
+    ("ZEUR", "EUR"),
+    ("ZGBP", "GBP"),
+    ("ZCAD", "CAD"),
+    ("ZJPY", "JPY"),
+];
+
+pub struct Kraken {
+    config: Configuration,
+    api_secret: String,
+}
+
+/// Whether an asset code holds a staked or earning balance.
+fn is_earn_code(code: &str) -> bool {
+    code.contains('.') || code == "ETH2"
+}
+
+#[async_trait]
+impl Exchange for Kraken {
+    fn name(&self) -> &'static str {
+        "Kraken"
+    }
+
+    fn from_env(user: Option<&str>) -> Option<Self> {
+        let key = oauth::user_var("KRAKEN_API_KEY", user)?;
+
+        let mut config = Configuration {
+            api_key: Some(ApiKey { key }),
+            client: http::client(),
+            user_agent: Some(http::user_agent()),
+            ..Default::default()
+        };
+
+        if let Some(base_path) = std::env::var("KRAKEN_API_URL")
+            .ok()
+            .filter(|u| !u.is_empty())
+        {
+            config.base_path = base_path.trim_end_matches('/').to_string();
+        }
+
+        let Some(api_secret) = oauth::user_var("KRAKEN_API_SECRET", user) else {
+            error!("Kraken API key set without its secret, skipping Kraken");
+            return None;
+        };
+
+        Some(Kraken { config, api_secret })
+    }
+
+    /// Every balance under its asset code, including staked and earning
+    /// balances, e.g. `DOT.S`, from a single request.
+    async fn balances(&self) -> Result<Vec<Balance>, ExchangeError> {
+        let balances = get_extended_balance(&self.config, &self.api_secret).await?;
+
+        balances
+            .result
+            .into_iter()
+            .map(|(code, balance)| Ok(Balance::new(&code, balance.balance.parse()?)))
+            .collect()
+    }
+
+    async fn spot_balances(&self) -> Result<Vec<Balance>, ExchangeError> {
+        let mut balances = self.balances().await?;
+        balances.retain(|b| !is_earn_code(&b.asset));
+        Ok(balances)
+    }
+
+    async fn earn_balances(&self) -> Result<Vec<Balance>, ExchangeError> {
+        let mut balances = self.balances().await?;
+        balances.retain(|b| is_earn_code(&b.asset));
+        Ok(balances)
+    }
+
+    /// Maps a Kraken asset code to its canonical symbol, dropping the suffixes
+    /// of staked and earning balances, e.g. `XXBT` to `BTC` and `DOT.S` to
+    /// `DOT`.
+    fn normalize_symbol(&self, asset: &str) -> String {
+        let code = asset.split_once('.').map_or(asset, |(code, _)| code);
+
+        LEGACY_CODES
+            .iter()
+            .find(|(legacy, _)| *legacy == code)
+            .map_or(code, |(_, symbol)| symbol)
+            .to_string()
+    }
+
+    async fn price_usd(&self, symbol: &str) -> Result<f64, ExchangeError> {
+        // kraken still names pairs after the legacy codes
+        let base = match symbol {
+            "BTC" => "XBT",
+            "DOGE" => "XDG",
+            _ => symbol,
+        };
+
+        let ticker = get_ticker_information(&self.config, &format!("{base}USD")).await?;
+
+        let price = ticker
+            .result
+            .into_values()
+            .next()
+            .and_then(|t| t.last_price().map(str::to_string))
+            .ok_or_else(|| format!("No {base}USD ticker on Kraken"))?;
+
+        Ok(price.parse()?)
+    }
+}
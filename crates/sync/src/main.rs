@@ -1,36 +1,65 @@
+mod algorand;
+mod beacon;
 mod binance;
-mod bitcoin;
+mod bybit;
+mod cardano;
+mod cex;
+mod coinbase;
+mod cosmos;
 mod evm;
 mod exchange;
+mod guard;
+mod history;
+mod http;
+mod kraken;
+mod manual;
+mod metrics;
+mod notify;
+mod oauth;
+mod performance;
+mod prices;
+mod report;
 mod solana;
-
-use crate::binance::get_binance_wallet_value;
-use crate::bitcoin::get_total_from_coinlore;
+mod tron;
+mod utxo;
+
+use crate::algorand::get_algorand_wallet_value;
+use crate::beacon::{get_validators_value, VALIDATORS_WALLET};
+use crate::cardano::get_cardano_wallet_value;
+use crate::cex::{configured_exchanges, get_exchange_wallet_value};
+use crate::cosmos::get_cosmos_wallet_value;
 use crate::evm::get_evm_wallet_net_worth;
 use crate::exchange::get_exchange_rate;
+use crate::guard::{Guard, GuardMode};
+use crate::history::History;
+use crate::manual::{get_manual_wallet_value, manual_wallets};
+use crate::notify::Triggers;
+use crate::report::{RunReport, WalletReport, WalletValue};
 use crate::solana::get_solana_wallet_net_worth;
+use crate::tron::get_tron_wallet_value;
+use crate::utxo::get_utxo_wallet_value;
 use alloy_primitives::Address;
 use chrono::Utc;
 use dotenv::dotenv;
 use solana_sdk::pubkey::Pubkey;
-use std::collections::HashMap;
 use std::env;
+use std::fmt::Display;
+use std::future::Future;
 use std::str::FromStr;
+use std::time::Duration;
 use thiserror::Error;
 use tokio::join;
 use tracing::{error, info, warn};
 use tracing_subscriber::EnvFilter;
-use uuid::Uuid;
-use ynab_api::apis::accounts_api::{create_account, get_accounts};
-use ynab_api::apis::budgets_api::get_budgets;
 use ynab_api::apis::configuration::Configuration;
-use ynab_api::apis::transactions_api::{
-    create_transaction, get_transactions_by_account, update_transaction,
-};
-use ynab_api::models::{
-    AccountType, ExistingTransaction, NewTransaction, PostAccountWrapper, PostTransactionsWrapper,
-    PutTransactionWrapper, SaveAccount, TransactionClearedStatus,
+use ynab_api::client::{
+    AccountId, BudgetHandle, BudgetId, BudgetMirror, Money, TransactionId, TransactionParams,
+    YnabClient,
 };
+use ynab_api::models::{Account, AccountType, TransactionClearedStatus, TransactionSummary};
+
+const DEFAULT_SYNC_INTERVAL_MINUTES: u64 = 60;
+const DEFAULT_YNAB_CACHE: &str = "ynab-cache.json";
 
 #[derive(Debug, Error)]
 pub enum SyncError {
@@ -38,12 +67,41 @@ pub enum SyncError {
     EnvVar(#[from] env::VarError),
     #[error("YNAB API error: {0}")]
     YnabApi(String),
+    #[error("YNAB rejected the access token: {0}")]
+    YnabAuth(String),
+    #[error("YNAB resource not found: {0}")]
+    YnabNotFound(String),
+    #[error("YNAB conflict: {0}")]
+    YnabConflict(String),
+    #[error("YNAB rate limit reached: {0}")]
+    YnabRateLimited(String),
+    #[error("YNAB rejected the request: {0}")]
+    YnabValidation(String),
     #[error("Exchange rate error: {0}")]
     ExchangeRate(String),
     #[error("Wallet value error: {0}")]
     WalletValue(String),
     #[error("Balance sync error: {0}")]
     BalanceSync(String),
+    #[error("History error: {0}")]
+    History(String),
+    #[error("OAuth error: {0}")]
+    OAuth(String),
+}
+
+impl<T> From<ynab_api::apis::Error<T>> for SyncError {
+    fn from(e: ynab_api::apis::Error<T>) -> Self {
+        let message = e.to_string();
+
+        match e.status().map(|s| s.as_u16()) {
+            Some(401 | 403) => SyncError::YnabAuth(message),
+            Some(404) => SyncError::YnabNotFound(message),
+            Some(409) => SyncError::YnabConflict(message),
+            Some(429) => SyncError::YnabRateLimited(message),
+            Some(400 | 422) => SyncError::YnabValidation(message),
+            _ => SyncError::YnabApi(message),
+        }
+    }
 }
 
 #[tokio::main]
@@ -59,10 +117,47 @@ async fn main() -> Result<(), SyncError> {
     dotenv().ok();
     setup_tracing();
 
-    let ynab_token = env::var("YNAB_ACCESS_TOKEN").unwrap_or_else(|_| {
-        error!("YNAB API key is missing. Exiting...");
+    if let Err(e) = http::init() {
+        error!("Invalid HTTP settings: {e}. Exiting...");
+        std::process::exit(1);
+    }
+
+    let daemon = match env::args().nth(1).as_deref() {
+        None | Some("sync") => false,
+        Some("daemon") => true,
+        Some("report") => {
+            return performance::print_report(env::args().nth(2).as_deref())
+                .map_err(|e| SyncError::History(e.to_string()));
+        }
+        Some("login") => {
+            let Some(user) = env::args().nth(2) else {
+                error!("Missing user name, usage: `sync login <name>`. Exiting...");
+                std::process::exit(1);
+            };
+            return oauth::login(&user)
+                .await
+                .map_err(|e| SyncError::OAuth(e.to_string()));
+        }
+        Some(command) => {
+            error!(
+                "Unknown command `{command}`. Expected `sync`, `daemon`, `report` or `login`. Exiting..."
+            );
+            std::process::exit(1);
+        }
+    };
+
+    let ynab_token = env::var("YNAB_ACCESS_TOKEN").ok().filter(|t| !t.is_empty());
+
+    let oauth_users = oauth::Credentials::load()
+        .map(|credentials| credentials.users.into_keys().collect::<Vec<_>>())
+        .unwrap_or_default();
+
+    if ynab_token.is_none() && oauth_users.is_empty() {
+        error!(
+            "YNAB API key is missing. Set YNAB_ACCESS_TOKEN or run `sync login <name>`. Exiting..."
+        );
         std::process::exit(1)
-    });
+    }
 
     info!("Starting crypto portfolio sync...");
 
@@ -73,30 +168,182 @@ async fn main() -> Result<(), SyncError> {
         "Crypto".to_string()
     });
 
-    let config = ynab_config(&ynab_token);
+    // seeded by user name, the tokens are only refreshed when syncing
+    let users = ynab_token
+        .iter()
+        .map(|_| None)
+        .chain(oauth_users.into_iter().map(Some));
+
+    for user in users {
+        if let Err(e) = History::open(user.as_deref())
+            .and_then(|history| metrics::seed(&history, user.as_deref()))
+        {
+            error!("Failed to restore metrics from history: {}", e);
+        }
+    }
 
-    let budget = get_budget(&config).await?;
-    let currency = budget
-        .currency_format
-        .unwrap_or_default()
-        .unwrap_or_default()
-        .iso_code;
+    if !daemon {
+        let result = run_all(ynab_token.as_deref(), &ynab_account_name).await;
+
+        if let Some(path) = env::var("METRICS_TEXTFILE").ok().filter(|p| !p.is_empty()) {
+            if let Err(e) = metrics::write_textfile(&path) {
+                error!("Failed to write metrics: {}", e);
+            }
+        }
+
+        return result;
+    }
+
+    let interval = env::var("SYNC_INTERVAL_MINUTES")
+        .ok()
+        .and_then(|m| m.parse::<u64>().ok())
+        .unwrap_or(DEFAULT_SYNC_INTERVAL_MINUTES);
+
+    if let Some(addr) = env::var("METRICS_ADDR").ok().filter(|a| !a.is_empty()) {
+        tokio::spawn(metrics::serve(addr));
+    }
+
+    loop {
+        if let Err(e) = run_all(ynab_token.as_deref(), &ynab_account_name).await {
+            error!("Sync failed: {}", e);
+        }
+
+        info!("Next sync in {interval} minutes.");
+        tokio::time::sleep(Duration::from_secs(interval * 60)).await;
+    }
+}
 
-    info!("Getting accounts for budget {}...", budget.id);
+/// Returns the YNAB clients to sync with: the personal access token's and
+/// those of the users logged in with OAuth, by user name.
+async fn targets(ynab_token: Option<&str>) -> Vec<(Option<String>, YnabClient)> {
+    let mut targets = vec![];
 
-    let account =
-        get_or_create_account(&config, &budget.id.to_string(), &ynab_account_name).await?;
+    if let Some(token) = ynab_token {
+        targets.push((None, ynab_client(token)));
+    }
+
+    match oauth::Credentials::load() {
+        Ok(mut credentials) => {
+            for (user, token) in credentials.access_tokens().await {
+                targets.push((Some(user), ynab_client(token)));
+            }
+        }
+        Err(e) => error!("Failed to load YNAB credentials: {}", e),
+    }
+
+    targets
+}
+
+/// Creates a client authorized with a token, calling the API at
+/// `YNAB_API_URL` when set.
+fn ynab_client(token: impl Into<String>) -> YnabClient {
+    let mut configuration = Configuration::new();
+    configuration.bearer_access_token = Some(token.into());
+    configuration.client = http::client();
+    configuration.user_agent = Some(http::user_agent());
+
+    if let Some(url) = env::var("YNAB_API_URL").ok().filter(|u| !u.is_empty()) {
+        configuration.base_path = url.trim_end_matches('/').to_string();
+    }
+
+    configuration.on_request = Some(metrics::ynab_request_hook(&configuration.base_path));
+
+    YnabClient::with_configuration(configuration)
+}
+
+/// Syncs the budget of every target in turn. Returns the first error, after
+/// logging the others.
+async fn run_all(ynab_token: Option<&str>, ynab_account_name: &str) -> Result<(), SyncError> {
+    let targets = targets(ynab_token).await;
+
+    if targets.is_empty() {
+        return Err(SyncError::OAuth("No valid YNAB token".to_string()));
+    }
 
-    let txns = get_transactions_by_account(
-        &config,
-        &budget.id.to_string(),
-        &account.id.to_string(),
-        None,
-        None,
-        None,
-    )
-    .await
-    .map_err(|e| SyncError::YnabApi(e.to_string()))?;
+    let mut result = Ok(());
+
+    for (user, client) in targets {
+        if let Some(user) = &user {
+            info!("Syncing the budget of {user}...");
+        }
+
+        if let Err(e) = run(&client, user.as_deref(), ynab_account_name).await {
+            if result.is_ok() {
+                result = Err(e);
+            } else {
+                error!("Sync failed: {}", e);
+            }
+        }
+    }
+
+    result
+}
+
+/// Runs a single sync and publishes its report, history and metrics.
+async fn run(
+    client: &YnabClient,
+    user: Option<&str>,
+    ynab_account_name: &str,
+) -> Result<(), SyncError> {
+    let mut report = RunReport::new(user);
+
+    let mut history = History::open(user)
+        .map_err(|e| error!("Failed to open run history: {}", e))
+        .ok();
+
+    let result = sync(client, ynab_account_name, history.as_ref(), &mut report).await;
+
+    if let Err(e) = &result {
+        report.errors.push(e.to_string());
+    }
+
+    if let Err(e) = report.publish() {
+        error!("Failed to write run report: {}", e);
+    }
+
+    let notification = notify::evaluate(&report, history.as_ref(), &Triggers::from_env());
+
+    if let Some(history) = history.as_mut() {
+        if let Err(e) = history.record(&report) {
+            error!("Failed to record run history: {}", e);
+        }
+    }
+
+    metrics::observe_run(&report, client.configuration());
+
+    if let Some(notification) = notification {
+        notify::send(&notify::notifiers(), &notification).await;
+    }
+
+    if result.is_ok() {
+        info!("Sync completed successfully!");
+    }
+
+    result
+}
+
+async fn sync(
+    client: &YnabClient,
+    ynab_account_name: &str,
+    history: Option<&History>,
+    report: &mut RunReport,
+) -> Result<(), SyncError> {
+    let budget_summary = get_budget(client).await?;
+    let currency = budget_summary
+        .currency()
+        .map(|c| c.iso_code.clone())
+        .unwrap_or_default();
+
+    report.budget_id = Some(budget_summary.id.to_string());
+    report.currency = Some(currency.clone());
+
+    info!("Refreshing the cache of budget {}...", budget_summary.id);
+
+    let budget = client.budget(budget_summary.id);
+    let mirror = refresh_mirror(client, budget_summary.budget_id(), report.user.as_deref()).await?;
+    let account = get_or_create_account(&budget, &mirror, ynab_account_name).await?;
+
+    report.account_id = Some(account.id.to_string());
 
     info!("Getting exchange rate...");
 
@@ -104,259 +351,442 @@ async fn main() -> Result<(), SyncError> {
         .await
         .map_err(|e| SyncError::ExchangeRate(e.to_string()))?;
 
-    let values = get_wallet_balances().await?;
+    report.fx_rate = Some(rate);
+    report.wallets = get_wallet_balances(report.user.as_deref()).await?;
+
+    for wallet in report.wallets.iter_mut() {
+        wallet.value_fiat = wallet.value_usd.map(|total| total / rate);
+    }
+
+    let writes = report
+        .wallets
+        .iter()
+        .filter(|w| w.value_usd.is_some())
+        .count() as u32;
+    let remaining = client.remaining_requests();
+    if remaining < writes {
+        warn!("Only {remaining} YNAB requests left this hour for {writes} wallet updates");
+    }
+
+    let guard = Guard::from_env();
+    let today = Utc::now().date_naive().format("%Y-%m-%d").to_string();
+
+    if history.is_none() && guard.zero_value_confirmations > 1 {
+        warn!(
+            "No run history to confirm zero values with, every zero value will be flagged as suspicious"
+        );
+    }
+
+    for wallet in report.wallets.iter_mut() {
+        let Some(total) = wallet.value_usd else {
+            continue;
+        };
 
-    for (wallet, total) in values {
-        update_wallet_transaction(
-            &config,
-            &budget.id.to_string(),
-            &account.id,
-            &wallet,
+        let zero_runs = history
+            .and_then(|h| {
+                h.consecutive_zero_values(&wallet.source, &wallet.wallet)
+                    .ok()
+            })
+            .unwrap_or_default()
+            + 1;
+
+        if let Some(reason) = guard.check(
+            balance_before(&mirror, account.account_id(), &wallet.wallet, &today).milliunits(),
+            to_money(total, rate).milliunits(),
+            zero_runs,
+        ) {
+            wallet.suspicious = Some(reason.clone());
+
+            if guard.mode == GuardMode::Quarantine {
+                warn!("Quarantining value of {}: {}", wallet.wallet, reason);
+                wallet.quarantined = true;
+                continue;
+            }
+
+            warn!("Suspicious value for {}: {}", wallet.wallet, reason);
+        }
+
+        match update_wallet_transaction(
+            &budget,
+            &mirror,
+            account.account_id(),
+            &wallet.wallet,
             total,
             rate,
-            &txns,
         )
-        .await?;
+        .await
+        {
+            Ok((transaction_id, delta)) => {
+                wallet.transaction_id = transaction_id.map(|id| id.to_string());
+                wallet.delta = Some(delta.milliunits());
+            }
+            Err(e) => {
+                wallet.error = Some(e.to_string());
+                return Err(e);
+            }
+        }
     }
 
-    info!("Sync completed successfully!");
     Ok(())
 }
 
-async fn get_budget(
-    config: &Configuration,
-) -> Result<Box<ynab_api::models::BudgetSummary>, SyncError> {
-    let budgets = get_budgets(config, Some(true))
-        .await
-        .map_err(|e| SyncError::YnabApi(e.to_string()))?;
+async fn get_budget(client: &YnabClient) -> Result<ynab_api::models::BudgetSummary, SyncError> {
+    info!("Using the default or first budget...");
 
-    info!(
-        "Got {} budgets. Using the default or first one...",
-        budgets.data.budgets.len()
-    );
+    client
+        .default_budget()
+        .await?
+        .ok_or_else(|| SyncError::YnabApi("No budget found".to_string()))
+}
+
+/// Brings the local copy of a budget at `YNAB_CACHE`, or the user's own copy
+/// of it, up to date with a delta request.
+async fn refresh_mirror(
+    client: &YnabClient,
+    budget_id: BudgetId,
+    user: Option<&str>,
+) -> Result<BudgetMirror, SyncError> {
+    let path = env::var("YNAB_CACHE")
+        .ok()
+        .filter(|p| !p.is_empty())
+        .unwrap_or_else(|| DEFAULT_YNAB_CACHE.to_string());
+    let path = oauth::user_path(&path, user);
+
+    let mut mirror = BudgetMirror::load(&path, budget_id.clone()).unwrap_or_else(|e| {
+        warn!("Failed to load the YNAB cache from {path}, fetching the whole budget: {e}");
+        BudgetMirror::new(budget_id)
+    });
+
+    let changes = mirror.refresh(client).await?;
+
+    info!("Got {changes} changes to the budget");
 
-    Ok(budgets
-        .data
-        .default_budget
-        .clone()
-        .unwrap_or_else(|| Box::new(budgets.data.budgets.first().unwrap().clone())))
+    if let Err(e) = mirror.save(&path) {
+        warn!("Failed to save the YNAB cache to {path}: {e}");
+    }
+
+    Ok(mirror)
 }
 
 async fn get_or_create_account(
-    config: &Configuration,
-    budget_id: &str,
+    budget: &BudgetHandle<'_>,
+    mirror: &BudgetMirror,
     account_name: &str,
-) -> Result<Box<ynab_api::models::Account>, SyncError> {
-    let accounts = get_accounts(config, budget_id, None)
-        .await
-        .map_err(|e| SyncError::YnabApi(e.to_string()))?;
-
-    info!("Got {} accounts", accounts.data.accounts.len());
-
-    if let Some(account) = accounts
-        .data
-        .accounts
-        .into_iter()
-        .find(|a| a.name.eq(account_name))
-    {
-        Ok(Box::new(account))
+) -> Result<Account, SyncError> {
+    if let Some(account) = mirror.accounts().find(|a| a.name.eq(account_name)) {
+        Ok(account.clone())
     } else {
         info!(
             "No YNAB account called `{}` found. Creating it now...",
             account_name
         );
 
-        let response = create_account(
-            config,
-            budget_id,
-            PostAccountWrapper {
-                account: Box::new(SaveAccount {
-                    name: account_name.to_string(),
-                    balance: 0,
-                    r#type: AccountType::OtherAsset,
-                }),
-            },
-        )
-        .await
-        .map_err(|e| SyncError::YnabApi(e.to_string()))?;
-
-        Ok(response.data.account)
+        budget
+            .accounts()
+            .create(account_name, AccountType::OtherAsset, Money::ZERO)
+            .await
+            .map_err(SyncError::from)
     }
 }
 
-async fn get_wallet_balances() -> Result<HashMap<String, f64>, SyncError> {
-    let evm_wallets = env::var("EVM_WALLETS")
+/// Values the wallets configured for a user.
+async fn get_wallet_balances(user: Option<&str>) -> Result<Vec<WalletReport>, SyncError> {
+    let evm_wallets = oauth::user_var("EVM_WALLETS", user)
         .unwrap_or_default()
         .split(',')
         .filter(|w| Address::from_str(w).is_ok())
         .map(ToString::to_string)
         .collect::<Vec<_>>();
 
-    let bitcoin_wallets = env::var("BTC_WALLETS")
+    let (utxo_wallets, conflicting_utxo_wallets) = utxo::configured_wallets(user);
+
+    let solana_wallets = oauth::user_var("SOLANA_WALLETS", user)
         .unwrap_or_default()
         .split(',')
-        .filter(|w| ::bitcoin::Address::from_str(w).is_ok())
+        .filter(|w| Pubkey::from_str(w).is_ok())
         .map(ToString::to_string)
         .collect::<Vec<_>>();
 
-    let solana_wallets = env::var("SOLANA_WALLETS")
+    let algorand_wallets = oauth::user_var("ALGORAND_WALLETS", user)
         .unwrap_or_default()
         .split(',')
-        .filter(|w| Pubkey::from_str(w).is_ok())
+        .filter(|w| algorand::is_valid_address(w))
+        .map(ToString::to_string)
+        .collect::<Vec<_>>();
+
+    let cardano_wallets = cardano::configured_wallets(user);
+
+    let tron_wallets = oauth::user_var("TRON_WALLETS", user)
+        .unwrap_or_default()
+        .split(',')
+        .filter(|w| tron::is_valid_address(w))
+        .map(ToString::to_string)
+        .collect::<Vec<_>>();
+
+    let validators = oauth::user_var("ETH_VALIDATORS", user)
+        .unwrap_or_default()
+        .split(',')
+        .filter(|v| beacon::is_valid_validator_id(v))
+        .map(ToString::to_string)
+        .collect::<Vec<_>>();
+
+    let cosmos_chains = cosmos::chains();
+    let cosmos_wallets = oauth::user_var("COSMOS_WALLETS", user)
+        .unwrap_or_default()
+        .split(',')
+        .filter(|w| cosmos::chain_for_address(w, &cosmos_chains).is_some())
         .map(ToString::to_string)
         .collect::<Vec<_>>();
 
     info!("Getting wallet balances...");
 
     let evm_values = async {
-        let mut values = HashMap::new();
+        let mut values = vec![];
         for wallet in &evm_wallets {
-            match get_evm_wallet_net_worth(wallet).await {
-                Ok(Some(total)) => {
-                    values.insert(wallet.clone(), total);
-                }
-                Ok(None) => {
-                    warn!("No value returned for EVM wallet: {}", wallet);
-                }
-                Err(e) => {
-                    error!("Error getting EVM wallet value for {}: {}", wallet, e);
-                }
-            }
+            values.push(fetch_wallet("EVM", wallet, get_evm_wallet_net_worth(wallet)).await);
         }
         values
     };
 
-    let bitcoin_values = async {
-        let mut values = HashMap::new();
-        for wallet in &bitcoin_wallets {
-            match get_total_from_coinlore(wallet).await {
-                Ok(Some(total)) => {
-                    values.insert(wallet.clone(), total);
-                }
-                Ok(None) => {
-                    warn!("No value returned for Bitcoin wallet: {}", wallet);
-                }
-                Err(e) => {
-                    error!("Error getting Bitcoin wallet value for {}: {}", wallet, e);
-                }
-            }
+    let utxo_values = async {
+        let mut values = vec![];
+        for (network, wallet) in &utxo_wallets {
+            values.push(
+                fetch_wallet(network.name, wallet, get_utxo_wallet_value(network, wallet)).await,
+            );
         }
         values
     };
 
     let solana_values = async {
-        let mut values = HashMap::new();
+        let mut values = vec![];
         for wallet in &solana_wallets {
-            match get_solana_wallet_net_worth(wallet).await {
-                Ok(Some(total)) => {
-                    values.insert(wallet.clone(), total);
-                }
-                Ok(None) => {
-                    warn!("No value returned for Solana wallet: {}", wallet);
-                }
-                Err(e) => {
-                    error!("Error getting Solana wallet value for {}: {}", wallet, e);
-                }
-            }
+            values.push(fetch_wallet("Solana", wallet, get_solana_wallet_net_worth(wallet)).await);
         }
         values
     };
 
-    let (evm_results, bitcoin_results, solana_results) =
-        join!(evm_values, bitcoin_values, solana_values);
+    let algorand_values = async {
+        let mut values = vec![];
+        for wallet in &algorand_wallets {
+            values.push(fetch_wallet("Algorand", wallet, get_algorand_wallet_value(wallet)).await);
+        }
+        values
+    };
+
+    let cardano_values = async {
+        let mut values = vec![];
+        for wallet in &cardano_wallets {
+            values.push(fetch_wallet("Cardano", wallet, get_cardano_wallet_value(wallet)).await);
+        }
+        values
+    };
+
+    let tron_values = async {
+        let mut values = vec![];
+        for wallet in &tron_wallets {
+            values.push(fetch_wallet("Tron", wallet, get_tron_wallet_value(wallet)).await);
+        }
+        values
+    };
+
+    let cosmos_values = async {
+        let mut values = vec![];
+        for wallet in &cosmos_wallets {
+            values.push(fetch_wallet("Cosmos", wallet, get_cosmos_wallet_value(wallet)).await);
+        }
+        values
+    };
 
-    let mut values: HashMap<String, f64> = evm_results
+    let (
+        evm_results,
+        utxo_results,
+        solana_results,
+        algorand_results,
+        cardano_results,
+        tron_results,
+        cosmos_results,
+    ) = join!(
+        evm_values,
+        utxo_values,
+        solana_values,
+        algorand_values,
+        cardano_values,
+        tron_values,
+        cosmos_values
+    );
+
+    let conflicting_utxo_values = conflicting_utxo_wallets.iter().map(|(network, wallet)| {
+        error!(
+            "{} wallet {} is configured on several networks, remove it from all but one",
+            network.name, wallet
+        );
+        let mut report = WalletReport::new(network.name, wallet);
+        report.error = Some("Address configured on several networks".to_string());
+        report
+    });
+
+    let mut values: Vec<WalletReport> = evm_results
         .into_iter()
-        .chain(bitcoin_results)
+        .chain(utxo_results)
+        .chain(conflicting_utxo_values)
         .chain(solana_results)
+        .chain(algorand_results)
+        .chain(cardano_results)
+        .chain(tron_results)
+        .chain(cosmos_results)
         .collect();
 
-    if env::var("BINANCE_API_KEY").is_ok() {
-        info!("Getting binance wallet value...");
-        match get_binance_wallet_value().await {
-            Ok(value) => {
-                values.insert("Binance".to_string(), value);
-            }
-            Err(e) => {
-                error!("Error getting Binance wallet value: {}", e);
+    if !validators.is_empty() {
+        info!("Getting validators value...");
+        values.push(
+            fetch_wallet(
+                "Beacon",
+                VALIDATORS_WALLET,
+                get_validators_value(&validators),
+            )
+            .await,
+        );
+    }
+
+    for (label, assets) in manual_wallets(user) {
+        values.push(fetch_wallet("Manual", &label, get_manual_wallet_value(&assets)).await);
+    }
+
+    for exchange in configured_exchanges(user) {
+        let name = exchange.name();
+        info!("Getting {} wallet value...", name.to_lowercase());
+        values.push(
+            fetch_wallet(name, name, async {
+                get_exchange_wallet_value(exchange.as_ref()).await.map(Some)
+            })
+            .await,
+        );
+    }
+
+    Ok(values)
+}
+
+/// Values a wallet with its source and builds its report entry, logging
+/// missing values and errors.
+async fn fetch_wallet<E: Display>(
+    source: &str,
+    wallet: &str,
+    fetch: impl Future<Output = Result<Option<WalletValue>, E>>,
+) -> WalletReport {
+    let mut report = WalletReport::new(source, wallet);
+
+    let timer = metrics::SOURCE_FETCH_DURATION
+        .with_label_values(&[source])
+        .start_timer();
+    let result = fetch.await;
+    timer.observe_duration();
+
+    match result {
+        Ok(Some(value)) => {
+            report.value_usd = Some(value.total_usd);
+            report.assets = value.assets;
+
+            // the priced assets are still written, but the wallet is reported
+            // as undervalued
+            if !value.unpriced.is_empty() {
+                report.error = Some(format!(
+                    "No price found for {}, left out of the value",
+                    value.unpriced.join(", ")
+                ));
             }
         }
+        Ok(None) => {
+            warn!("No value returned for {} wallet: {}", source, wallet);
+            report.error = Some("No value returned".to_string());
+        }
+        Err(e) => {
+            error!(
+                "Error getting {} wallet value for {}: {}",
+                source, wallet, e
+            );
+            report.error = Some(e.to_string());
+        }
     }
 
-    Ok(values)
+    report
 }
 
 async fn update_wallet_transaction(
-    config: &Configuration,
-    budget_id: &str,
-    account_id: &Uuid,
+    budget: &BudgetHandle<'_>,
+    mirror: &BudgetMirror,
+    account_id: AccountId,
     wallet: &str,
     total: f64,
     rate: f64,
-    txns: &ynab_api::models::TransactionsResponse,
-) -> Result<(), SyncError> {
+) -> Result<(Option<TransactionId>, Money), SyncError> {
     let today = Utc::now().date_naive();
 
     let today_str = today.format("%Y-%m-%d").to_string();
 
-    let todays_total = (total / rate * 1000.0).ceil() as i64;
+    let todays_total = to_money(total, rate);
 
-    let todays_txn = txns
-        .data
-        .transactions
-        .iter()
-        .filter(|t| t.payee_name == Some(Some(wallet.to_string())) && t.date.eq(&today_str))
-        .last();
+    let todays_txn =
+        wallet_transactions(mirror, account_id, wallet).find(|t| t.date.eq(&today_str));
 
-    let total_excluding_today: i64 = txns
-        .data
-        .transactions
-        .iter()
-        .filter(|t| t.payee_name == Some(Some(wallet.to_string())) && t.date.ne(&today_str))
-        .map(|t| t.amount)
-        .sum();
+    let total_excluding_today = balance_before(mirror, account_id, wallet, &today_str);
 
-    if let Some(todays_txn) = todays_txn {
-        info!("Balance for {wallet} exists for today. Updating amount...");
+    let delta = todays_total - total_excluding_today;
 
-        let put_txn: PutTransactionWrapper = PutTransactionWrapper {
-            transaction: Box::new(ExistingTransaction {
-                amount: Some(todays_total - total_excluding_today),
-                cleared: Some(TransactionClearedStatus::Cleared),
-                ..Default::default()
-            }),
-        };
+    let transaction_id = if let Some(todays_txn) = todays_txn {
+        info!("Balance for {wallet} exists for today. Updating amount...");
 
-        update_transaction(config, budget_id, &todays_txn.id.to_string(), put_txn)
-            .await
-            .map_err(|e| SyncError::YnabApi(e.to_string()))?;
+        budget
+            .transactions()
+            .update(
+                &todays_txn.transaction_id(),
+                TransactionParams::new()
+                    .amount(delta)
+                    .cleared(TransactionClearedStatus::Cleared),
+            )
+            .await?;
+
+        Some(todays_txn.transaction_id())
     } else {
-        create_transaction(
-            config,
-            budget_id,
-            PostTransactionsWrapper {
-                transaction: Some(Box::new(NewTransaction {
-                    account_id: Some(account_id.clone()),
-                    date: Some(today_str),
-                    amount: Some(todays_total - total_excluding_today),
-                    payee_name: Some(Some(wallet.to_string())),
-                    cleared: Some(TransactionClearedStatus::Cleared),
-                    ..Default::default()
-                })),
-                ..Default::default()
-            },
-        )
-        .await
-        .map_err(|e| SyncError::YnabApi(e.to_string()))?;
-    }
+        budget
+            .transactions()
+            .create(
+                TransactionParams::new()
+                    .account(account_id)
+                    .date(today_str)
+                    .amount(delta)
+                    .payee_name(wallet)
+                    .cleared(TransactionClearedStatus::Cleared),
+            )
+            .await?
+            .map(|t| t.transaction_id())
+    };
 
-    Ok(())
+    Ok((transaction_id, delta))
 }
 
-fn ynab_config(bearer_access_token: &str) -> Configuration {
-    let mut config = Configuration::new();
-    config.bearer_access_token = Some(bearer_access_token.to_owned());
-    config
+/// Converts a USD value to the budget currency.
+fn to_money(total: f64, rate: f64) -> Money {
+    Money::from_milliunits((total / rate * 1000.0).ceil() as i64)
+}
+
+/// The transactions of a wallet in the account, which have it as payee.
+fn wallet_transactions<'a>(
+    mirror: &'a BudgetMirror,
+    account_id: AccountId,
+    wallet: &'a str,
+) -> impl Iterator<Item = &'a TransactionSummary> {
+    mirror
+        .transactions_by_account(account_id)
+        .filter(move |t| mirror.payee_name(t) == Some(wallet))
+}
+
+/// Returns the balance of a wallet from its adjustments dated before `date`.
+fn balance_before(mirror: &BudgetMirror, account_id: AccountId, wallet: &str, date: &str) -> Money {
+    wallet_transactions(mirror, account_id, wallet)
+        .filter(|t| t.date.as_str() < date)
+        .map(|t| t.amount_money())
+        .sum()
 }
 
 fn setup_tracing() {
@@ -364,9 +794,76 @@ fn setup_tracing() {
     let env_filter = EnvFilter::try_from_default_env()
         .or_else(|_| EnvFilter::try_new("INFO"))
         .unwrap();
+    // logs go to stderr so that stdout can carry the run report
     let subscriber = tracing_subscriber::fmt()
         .with_env_filter(env_filter)
+        .with_writer(std::io::stderr)
         .finish();
 
     tracing::subscriber::set_global_default(subscriber).expect("setting default subscriber failed");
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ynab_api::apis::{Error, ResponseContent};
+
+    fn response_error(status: u16) -> Error<()> {
+        Error::ResponseError(ResponseContent {
+            status: reqwest::StatusCode::from_u16(status).unwrap(),
+            content: String::new(),
+            entity: None,
+        })
+    }
+
+    #[test]
+    fn maps_ynab_statuses_to_sync_errors() {
+        for status in [401, 403] {
+            assert!(matches!(
+                SyncError::from(response_error(status)),
+                SyncError::YnabAuth(_)
+            ));
+        }
+        assert!(matches!(
+            SyncError::from(response_error(404)),
+            SyncError::YnabNotFound(_)
+        ));
+        assert!(matches!(
+            SyncError::from(response_error(409)),
+            SyncError::YnabConflict(_)
+        ));
+        assert!(matches!(
+            SyncError::from(response_error(429)),
+            SyncError::YnabRateLimited(_)
+        ));
+        for status in [400, 422] {
+            assert!(matches!(
+                SyncError::from(response_error(status)),
+                SyncError::YnabValidation(_)
+            ));
+        }
+        for status in [500, 503] {
+            assert!(matches!(
+                SyncError::from(response_error(status)),
+                SyncError::YnabApi(_)
+            ));
+        }
+    }
+
+    #[test]
+    fn maps_errors_without_a_status_to_api_errors() {
+        let error: Error<()> = Error::Io(std::io::Error::other("connection reset"));
+
+        assert!(matches!(SyncError::from(error), SyncError::YnabApi(_)));
+    }
+
+    #[test]
+    fn keeps_the_ynab_error_message() {
+        let error = SyncError::from(response_error(401));
+
+        assert_eq!(
+            error.to_string(),
+            "YNAB rejected the access token: error in response: status code 401 Unauthorized"
+        );
+    }
+}
@@ -0,0 +1,58 @@
+use crate::oauth;
+use crate::prices::get_price_usd;
+use crate::report::{AssetValue, WalletValue};
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::error::Error;
+use tracing::error;
+
+/// A holding that cannot be fetched, entered by hand.
+#[derive(Debug, Clone, Deserialize)]
+pub struct ManualAsset {
+    pub symbol: String,
+    pub quantity: f64,
+    /// Name of the wallet the asset is reported in. Assets sharing a label are
+    /// valued together.
+    pub label: String,
+}
+
+/// Returns a user's assets in `MANUAL_ASSETS`, a JSON array of
+/// `{"symbol", "quantity", "label"}` objects, grouped by label.
+pub fn manual_wallets(user: Option<&str>) -> BTreeMap<String, Vec<ManualAsset>> {
+    let mut wallets: BTreeMap<String, Vec<ManualAsset>> = BTreeMap::new();
+
+    let Some(json) = oauth::user_var("MANUAL_ASSETS", user) else {
+        return wallets;
+    };
+
+    match serde_json::from_str::<Vec<ManualAsset>>(&json) {
+        Ok(assets) => {
+            for asset in assets {
+                wallets.entry(asset.label.clone()).or_default().push(asset);
+            }
+        }
+        Err(e) => error!("Invalid MANUAL_ASSETS: {e}"),
+    }
+
+    wallets
+}
+
+pub async fn get_manual_wallet_value(
+    assets: &[ManualAsset],
+) -> Result<Option<WalletValue>, Box<dyn Error + Send + Sync>> {
+    let mut values = vec![];
+
+    for asset in assets {
+        let price = get_price_usd(&asset.symbol)
+            .await?
+            .ok_or_else(|| format!("No price found for {}", asset.symbol))?;
+
+        values.push(AssetValue::new(
+            &asset.symbol.to_uppercase(),
+            asset.quantity,
+            price,
+        ));
+    }
+
+    Ok(Some(WalletValue::from_assets(values)))
+}
@@ -0,0 +1,395 @@
+use crate::history::History;
+use crate::report::RunReport;
+use chrono::Utc;
+use prometheus::core::Collector;
+use prometheus::{
+    register_gauge_vec, register_histogram_vec, register_int_counter_vec, register_int_gauge_vec,
+    Encoder, GaugeVec, HistogramVec, IntCounterVec, IntGaugeVec, TextEncoder,
+};
+use std::collections::HashMap;
+use std::error::Error;
+use std::sync::LazyLock;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpListener;
+use tracing::{error, info};
+use ynab_api::apis::configuration::{Configuration, RequestHook};
+
+pub static WALLET_VALUE: LazyLock<GaugeVec> = LazyLock::new(|| {
+    register_gauge_vec!(
+        "crypto_sync_wallet_value",
+        "Value of a wallet in the budget currency",
+        &["user", "source", "wallet", "currency"]
+    )
+    .unwrap()
+});
+
+pub static WALLET_VALUE_USD: LazyLock<GaugeVec> = LazyLock::new(|| {
+    register_gauge_vec!(
+        "crypto_sync_wallet_value_usd",
+        "Value of a wallet in USD",
+        &["user", "source", "wallet"]
+    )
+    .unwrap()
+});
+
+pub static WALLET_LAST_SUCCESS: LazyLock<GaugeVec> = LazyLock::new(|| {
+    register_gauge_vec!(
+        "crypto_sync_wallet_last_success_timestamp_seconds",
+        "Unix time a wallet was last valued successfully",
+        &["user", "source", "wallet"]
+    )
+    .unwrap()
+});
+
+pub static SOURCE_FETCH_DURATION: LazyLock<HistogramVec> = LazyLock::new(|| {
+    register_histogram_vec!(
+        "crypto_sync_source_fetch_duration_seconds",
+        "Time taken to value a wallet",
+        &["source"],
+        vec![0.1, 0.5, 1.0, 2.5, 5.0, 10.0, 30.0, 60.0, 120.0]
+    )
+    .unwrap()
+});
+
+pub static SOURCE_ERRORS: LazyLock<IntCounterVec> = LazyLock::new(|| {
+    register_int_counter_vec!(
+        "crypto_sync_source_errors_total",
+        "Wallets that could not be valued",
+        &["source"]
+    )
+    .unwrap()
+});
+
+pub static QUARANTINED: LazyLock<IntCounterVec> = LazyLock::new(|| {
+    register_int_counter_vec!(
+        "crypto_sync_quarantined_total",
+        "Wallet values kept out of YNAB by the sanity checks",
+        &["source"]
+    )
+    .unwrap()
+});
+
+pub static YNAB_REQUESTS: LazyLock<IntCounterVec> = LazyLock::new(|| {
+    register_int_counter_vec!(
+        "crypto_sync_ynab_requests_total",
+        "Requests made to the YNAB API, retries included, by method and route",
+        &["endpoint"]
+    )
+    .unwrap()
+});
+
+pub static YNAB_RATE_LIMIT_REMAINING: LazyLock<IntGaugeVec> = LazyLock::new(|| {
+    register_int_gauge_vec!(
+        "crypto_sync_ynab_rate_limit_remaining",
+        "Requests left in the current YNAB rate limit window of a user's token",
+        &["user"]
+    )
+    .unwrap()
+});
+
+pub static RUNS: LazyLock<IntCounterVec> = LazyLock::new(|| {
+    register_int_counter_vec!(
+        "crypto_sync_runs_total",
+        "Sync runs of a user's budget",
+        &["user", "result"]
+    )
+    .unwrap()
+});
+
+pub static LAST_SUCCESS: LazyLock<GaugeVec> = LazyLock::new(|| {
+    register_gauge_vec!(
+        "crypto_sync_last_success_timestamp_seconds",
+        "Unix time of the last sync of a user's budget that completed without errors",
+        &["user"]
+    )
+    .unwrap()
+});
+
+/// Restores the last success timestamps recorded in a user's history database,
+/// so they survive restarts and one-shot runs.
+pub fn seed(history: &History, user: Option<&str>) -> rusqlite::Result<()> {
+    let user = user.unwrap_or_default();
+
+    if let Some(at) = history.last_successful_run()? {
+        LAST_SUCCESS
+            .with_label_values(&[user])
+            .set(at.timestamp() as f64);
+    }
+
+    for (source, wallet, at) in history.last_wallet_successes()? {
+        WALLET_LAST_SUCCESS
+            .with_label_values(&[user, &source, &wallet])
+            .set(at.timestamp() as f64);
+    }
+
+    Ok(())
+}
+
+/// Updates the metrics with the outcome of a run.
+pub fn observe_run(report: &RunReport, config: &Configuration) {
+    let now = Utc::now().timestamp() as f64;
+    let user = report.user.as_deref().unwrap_or_default();
+    let currency = report.currency.clone().unwrap_or_default();
+
+    // the other users' budgets keep the values of their own last run
+    reset_user(&WALLET_VALUE, user);
+    reset_user(&WALLET_VALUE_USD, user);
+
+    for wallet in &report.wallets {
+        if let Some(value) = wallet.value_usd {
+            WALLET_VALUE_USD
+                .with_label_values(&[user, &wallet.source, &wallet.wallet])
+                .set(value);
+            WALLET_LAST_SUCCESS
+                .with_label_values(&[user, &wallet.source, &wallet.wallet])
+                .set(now);
+        }
+
+        if let Some(value) = wallet.value_fiat {
+            WALLET_VALUE
+                .with_label_values(&[user, &wallet.source, &wallet.wallet, &currency])
+                .set(value);
+        }
+
+        if wallet.error.is_some() {
+            SOURCE_ERRORS.with_label_values(&[&wallet.source]).inc();
+        }
+
+        if wallet.quarantined {
+            QUARANTINED.with_label_values(&[&wallet.source]).inc();
+        }
+    }
+
+    YNAB_RATE_LIMIT_REMAINING
+        .with_label_values(&[user])
+        .set(config.remaining_requests() as i64);
+
+    if report.errors.is_empty() {
+        RUNS.with_label_values(&[user, "success"]).inc();
+        LAST_SUCCESS.with_label_values(&[user]).set(now);
+    } else {
+        RUNS.with_label_values(&[user, "failure"]).inc();
+    }
+}
+
+/// A hook counting every request made with a YNAB client whose API is at
+/// `base_path`.
+pub fn ynab_request_hook(base_path: &str) -> RequestHook {
+    let prefix = reqwest::Url::parse(base_path)
+        .map(|url| url.path().trim_end_matches('/').to_string())
+        .unwrap_or_default();
+
+    RequestHook::new(move |method, url| {
+        YNAB_REQUESTS
+            .with_label_values(&[&format!("{method} {}", ynab_route(&prefix, url))])
+            .inc();
+    })
+}
+
+/// The route of a YNAB API request, with the ids and dates in its path left
+/// out so each endpoint is a single series, e.g. `/budgets/{id}/transactions`.
+fn ynab_route(prefix: &str, url: &reqwest::Url) -> String {
+    let path = url.path();
+
+    path.strip_prefix(prefix)
+        .unwrap_or(path)
+        .split('/')
+        .map(|segment| {
+            if segment.chars().any(|c| c.is_ascii_digit()) {
+                "{id}"
+            } else {
+                segment
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+/// Removes the series of a user from a wallet gauge.
+fn reset_user(gauge: &GaugeVec, user: &str) {
+    for family in gauge.collect() {
+        for metric in family.get_metric() {
+            let labels = metric
+                .get_label()
+                .iter()
+                .map(|l| (l.name(), l.value()))
+                .collect::<HashMap<_, _>>();
+
+            if labels.get("user") == Some(&user) {
+                let _ = gauge.remove(&labels);
+            }
+        }
+    }
+}
+
+/// Encodes all metrics in the Prometheus text format.
+pub fn encode() -> String {
+    let mut buffer = vec![];
+
+    TextEncoder::new()
+        .encode(&prometheus::gather(), &mut buffer)
+        .expect("encoding metrics should not fail");
+
+    String::from_utf8(buffer).expect("metrics should be valid UTF-8")
+}
+
+/// Writes the metrics for the node exporter textfile collector. The file is
+/// written next to its final location and renamed so it is never read half
+/// written.
+pub fn write_textfile(path: &str) -> Result<(), Box<dyn Error>> {
+    let tmp = format!("{path}.tmp");
+
+    std::fs::write(&tmp, encode())?;
+    std::fs::rename(&tmp, path)?;
+
+    info!("Metrics written to {path}");
+
+    Ok(())
+}
+
+/// Serves the metrics on `GET /metrics`.
+pub async fn serve(addr: String) {
+    let listener = match TcpListener::bind(&addr).await {
+        Ok(listener) => listener,
+        Err(e) => {
+            error!("Failed to bind metrics endpoint on {addr}: {e}");
+            return;
+        }
+    };
+
+    info!("Serving metrics on http://{addr}/metrics");
+
+    loop {
+        let Ok((mut stream, _)) = listener.accept().await else {
+            continue;
+        };
+
+        tokio::spawn(async move {
+            let mut buf = [0u8; 1024];
+            let n = stream.read(&mut buf).await.unwrap_or(0);
+            let request = String::from_utf8_lossy(&buf[..n]);
+
+            let response = if request.starts_with("GET /metrics ") {
+                let body = encode();
+                format!(
+                    "HTTP/1.1 200 OK\r\nContent-Type: text/plain; version=0.0.4\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                    body.len()
+                )
+            } else {
+                "HTTP/1.1 404 Not Found\r\nContent-Length: 0\r\nConnection: close\r\n\r\n"
+                    .to_string()
+            };
+
+            if let Err(e) = stream.write_all(response.as_bytes()).await {
+                error!("Failed to write metrics response: {e}");
+            }
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::report::WalletReport;
+    use prometheus::proto::MetricType;
+
+    fn run(user: &str, wallets: &[&str]) -> RunReport {
+        let mut report = RunReport::new(Some(user));
+        report.wallets = wallets
+            .iter()
+            .map(|wallet| {
+                let mut wallet = WalletReport::new("Manual", wallet);
+                wallet.value_usd = Some(1.0);
+                wallet
+            })
+            .collect();
+        report
+    }
+
+    /// The value of the series with the given labels, gauge or counter.
+    fn sample(collector: &impl Collector, labels: &[(&str, &str)]) -> Option<f64> {
+        let family = collector.collect().pop()?;
+        let metric = family.get_metric().iter().find(|metric| {
+            labels.iter().all(|(name, value)| {
+                metric
+                    .get_label()
+                    .iter()
+                    .any(|l| l.name() == *name && l.value() == *value)
+            })
+        })?;
+
+        Some(match family.get_field_type() {
+            MetricType::COUNTER => metric.get_counter().get_value(),
+            _ => metric.get_gauge().get_value(),
+        })
+    }
+
+    fn wallet_value(user: &str, wallet: &str) -> Option<f64> {
+        sample(&*WALLET_VALUE_USD, &[("user", user), ("wallet", wallet)])
+    }
+
+    #[test]
+    fn a_run_replaces_only_the_values_of_its_user() {
+        let config = Configuration::new();
+
+        observe_run(&run("metrics-alice", &["Savings", "Old"]), &config);
+        observe_run(&run("metrics-bob", &["Savings"]), &config);
+
+        let mut failed = run("metrics-alice", &["Savings"]);
+        failed.errors.push("YNAB is down".to_string());
+        observe_run(&failed, &config);
+
+        assert_eq!(wallet_value("metrics-alice", "Savings"), Some(1.0));
+        assert_eq!(wallet_value("metrics-alice", "Old"), None);
+        assert_eq!(wallet_value("metrics-bob", "Savings"), Some(1.0));
+
+        // bob's success does not hide alice's failure
+        let runs = |user, result| sample(&*RUNS, &[("user", user), ("result", result)]);
+        assert_eq!(runs("metrics-alice", "success"), Some(1.0));
+        assert_eq!(runs("metrics-alice", "failure"), Some(1.0));
+        assert_eq!(runs("metrics-bob", "success"), Some(1.0));
+        assert_eq!(runs("metrics-bob", "failure"), None);
+
+        let last_success = |user| sample(&*LAST_SUCCESS, &[("user", user)]);
+        assert!(last_success("metrics-alice") <= last_success("metrics-bob"));
+
+        for user in ["metrics-alice", "metrics-bob"] {
+            assert_eq!(
+                sample(&*YNAB_RATE_LIMIT_REMAINING, &[("user", user)]),
+                Some(config.remaining_requests() as f64)
+            );
+        }
+    }
+
+    #[test]
+    fn counts_ynab_requests_by_route() {
+        let hook = ynab_request_hook("https://api.ynab.com/v1/");
+        let url = |path: &str| reqwest::Url::parse(&format!("https://api.ynab.com{path}")).unwrap();
+        let requests = |endpoint| sample(&*YNAB_REQUESTS, &[("endpoint", endpoint)]);
+
+        let before = requests("PUT /budgets/{id}/transactions/{id}").unwrap_or_default();
+
+        hook.call(&reqwest::Method::GET, &url("/v1/budgets/last-used"));
+        hook.call(
+            &reqwest::Method::PUT,
+            &url("/v1/budgets/0b1ef3ab-0000-4000-8000-000000000001/transactions/7c1ef3ab-0000-4000-8000-000000000002"),
+        );
+        hook.call(
+            &reqwest::Method::PUT,
+            &url("/v1/budgets/0b1ef3ab-0000-4000-8000-000000000001/transactions/9d1ef3ab-0000-4000-8000-000000000003_2026-01-01"),
+        );
+
+        assert!(requests("GET /budgets/last-used").is_some());
+        assert_eq!(
+            requests("PUT /budgets/{id}/transactions/{id}"),
+            Some(before + 2.0)
+        );
+        assert_eq!(
+            ynab_route(
+                "",
+                &url("/budgets/0b1ef3ab-0000-4000-8000-000000000001/months/2026-01-01")
+            ),
+            "/budgets/{id}/months/{id}"
+        );
+    }
+}
@@ -0,0 +1,331 @@
+use crate::http;
+use chrono::{DateTime, Duration, Utc};
+use reqwest::Url;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::env;
+use std::error::Error;
+use std::io::Write;
+use std::path::Path;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpListener;
+use tracing::{info, warn};
+
+const AUTHORIZE_URL: &str = "https://app.ynab.com/oauth/authorize";
+const TOKEN_URL: &str = "https://app.ynab.com/oauth/token";
+const DEFAULT_REDIRECT_URI: &str = "http://localhost:8765/oauth/callback";
+const DEFAULT_CREDENTIALS: &str = "ynab-credentials.json";
+const LOGIN_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(300);
+/// Tokens are refreshed when they expire within this many minutes.
+const REFRESH_MARGIN_MINUTES: i64 = 5;
+
+/// The OAuth application registered in the YNAB developer settings.
+pub struct OAuthApp {
+    client_id: String,
+    client_secret: String,
+    redirect_uri: String,
+}
+
+impl OAuthApp {
+    /// Reads the application from `YNAB_CLIENT_ID`, `YNAB_CLIENT_SECRET` and
+    /// `YNAB_REDIRECT_URI`, if configured.
+    pub fn from_env() -> Option<Self> {
+        let var = |name: &str| env::var(name).ok().filter(|v| !v.is_empty());
+
+        Some(OAuthApp {
+            client_id: var("YNAB_CLIENT_ID")?,
+            client_secret: var("YNAB_CLIENT_SECRET")?,
+            redirect_uri: var("YNAB_REDIRECT_URI")
+                .unwrap_or_else(|| DEFAULT_REDIRECT_URI.to_string()),
+        })
+    }
+
+    async fn request_token(
+        &self,
+        grant: &[(&str, &str)],
+    ) -> Result<Credential, Box<dyn Error + Send + Sync>> {
+        let mut form = vec![
+            ("client_id", self.client_id.as_str()),
+            ("client_secret", self.client_secret.as_str()),
+            ("redirect_uri", self.redirect_uri.as_str()),
+        ];
+        form.extend_from_slice(grant);
+
+        let token = http::client()
+            .post(TOKEN_URL)
+            .form(&form)
+            .send()
+            .await?
+            .error_for_status()?
+            .json::<TokenResponse>()
+            .await?;
+
+        Ok(Credential {
+            access_token: token.access_token,
+            refresh_token: token.refresh_token,
+            expires_at: Utc::now() + Duration::seconds(token.expires_in),
+        })
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct TokenResponse {
+    access_token: String,
+    refresh_token: String,
+    expires_in: i64,
+}
+
+/// The tokens YNAB granted for one user.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Credential {
+    pub access_token: String,
+    refresh_token: String,
+    expires_at: DateTime<Utc>,
+}
+
+impl Credential {
+    fn expires_soon(&self) -> bool {
+        self.expires_at - Utc::now() < Duration::minutes(REFRESH_MARGIN_MINUTES)
+    }
+}
+
+/// The credentials of every user logged in, stored at `YNAB_CREDENTIALS`.
+pub struct Credentials {
+    path: String,
+    pub users: BTreeMap<String, Credential>,
+}
+
+impl Credentials {
+    pub fn load() -> Result<Self, Box<dyn Error + Send + Sync>> {
+        let path = env::var("YNAB_CREDENTIALS")
+            .ok()
+            .filter(|p| !p.is_empty())
+            .unwrap_or_else(|| DEFAULT_CREDENTIALS.to_string());
+
+        let users = if Path::new(&path).exists() {
+            serde_json::from_str(&std::fs::read_to_string(&path)?)?
+        } else {
+            BTreeMap::new()
+        };
+
+        Ok(Credentials { path, users })
+    }
+
+    /// Writes the credentials, readable by the current user only.
+    pub fn save(&self) -> Result<(), Box<dyn Error + Send + Sync>> {
+        let tmp = format!("{}.tmp", self.path);
+        let mut options = std::fs::OpenOptions::new();
+        options.write(true).create(true).truncate(true);
+
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::OpenOptionsExt;
+            options.mode(0o600);
+        }
+
+        // the mode only applies to new files, so drop a leftover from a crash
+        let _ = std::fs::remove_file(&tmp);
+        let mut file = options.open(&tmp)?;
+        file.write_all(serde_json::to_string_pretty(&self.users)?.as_bytes())?;
+        file.sync_all()?;
+        drop(file);
+
+        std::fs::rename(&tmp, &self.path)?;
+        Ok(())
+    }
+
+    /// Refreshes the tokens that are about to expire and saves them. Users
+    /// whose refresh fails are left out of the returned access tokens.
+    pub async fn access_tokens(&mut self) -> Vec<(String, String)> {
+        let app = OAuthApp::from_env();
+        let mut refreshed = false;
+        let mut tokens = vec![];
+
+        for (name, credential) in self.users.iter_mut() {
+            if credential.expires_soon() {
+                let Some(app) = &app else {
+                    warn!("Cannot refresh the YNAB token of {name}: YNAB_CLIENT_ID and YNAB_CLIENT_SECRET are not set");
+                    continue;
+                };
+
+                info!("Refreshing the YNAB token of {name}...");
+
+                match app
+                    .request_token(&[
+                        ("grant_type", "refresh_token"),
+                        ("refresh_token", credential.refresh_token.as_str()),
+                    ])
+                    .await
+                {
+                    Ok(fresh) => {
+                        *credential = fresh;
+                        refreshed = true;
+                    }
+                    Err(e) => {
+                        warn!("Failed to refresh the YNAB token of {name}, log in again with `sync login {name}`: {e}");
+                        continue;
+                    }
+                }
+            }
+
+            tokens.push((name.clone(), credential.access_token.clone()));
+        }
+
+        if refreshed {
+            if let Err(e) = self.save() {
+                warn!("Failed to save refreshed YNAB tokens to {}: {e}", self.path);
+            }
+        }
+
+        tokens
+    }
+}
+
+/// Runs the authorization-code flow for a user: prints the YNAB authorization
+/// URL, waits for the redirect on the loopback address, and stores the tokens.
+pub async fn login(name: &str) -> Result<(), Box<dyn Error + Send + Sync>> {
+    let app = OAuthApp::from_env().ok_or("YNAB_CLIENT_ID and YNAB_CLIENT_SECRET must be set")?;
+    let redirect = Url::parse(&app.redirect_uri)?;
+    let port = redirect
+        .port_or_known_default()
+        .ok_or("YNAB_REDIRECT_URI has no port")?;
+
+    let mut nonce = [0u8; 16];
+    getrandom::getrandom(&mut nonce)?;
+    let state = nonce.iter().map(|b| format!("{b:02x}")).collect::<String>();
+
+    let mut authorize = Url::parse(AUTHORIZE_URL)?;
+    authorize
+        .query_pairs_mut()
+        .append_pair("client_id", &app.client_id)
+        .append_pair("redirect_uri", &app.redirect_uri)
+        .append_pair("response_type", "code")
+        .append_pair("state", &state);
+
+    let listener = TcpListener::bind(("127.0.0.1", port)).await?;
+
+    info!("Open this URL to let the sync access {name}'s budgets:\n\n{authorize}\n");
+
+    let code = tokio::time::timeout(
+        LOGIN_TIMEOUT,
+        wait_for_code(&listener, redirect.path(), &state),
+    )
+    .await
+    .map_err(|_| "Timed out waiting for the YNAB authorization")??;
+
+    let credential = app
+        .request_token(&[
+            ("grant_type", "authorization_code"),
+            ("code", code.as_str()),
+        ])
+        .await?;
+
+    let mut credentials = Credentials::load()?;
+    credentials.users.insert(name.to_string(), credential);
+    credentials.save()?;
+
+    info!(
+        "Logged in {name}, credentials saved to {}",
+        credentials.path
+    );
+
+    Ok(())
+}
+
+/// Serves the loopback redirect until YNAB sends the authorization code.
+async fn wait_for_code(
+    listener: &TcpListener,
+    path: &str,
+    state: &str,
+) -> Result<String, Box<dyn Error + Send + Sync>> {
+    loop {
+        let (mut stream, _) = listener.accept().await?;
+
+        let mut buf = vec![0; 8192];
+        let read = stream.read(&mut buf).await?;
+        let request = String::from_utf8_lossy(&buf[..read]);
+
+        // request line: GET /oauth/callback?code=...&state=... HTTP/1.1
+        let target = request.split_whitespace().nth(1).unwrap_or_default();
+        let Ok(url) = Url::parse("http://localhost")?.join(target) else {
+            continue;
+        };
+
+        if url.path() != path {
+            stream
+                .write_all(b"HTTP/1.1 404 Not Found\r\nContent-Length: 0\r\n\r\n")
+                .await?;
+            continue;
+        }
+
+        let param = |key: &str| {
+            url.query_pairs()
+                .find(|(k, _)| k == key)
+                .map(|(_, v)| v.into_owned())
+        };
+
+        let (status, message, result) = if param("state").as_deref() != Some(state) {
+            ("400 Bad Request", "Invalid state.", None)
+        } else if let Some(error) = param("error") {
+            (
+                "400 Bad Request",
+                "Authorization denied.",
+                Some(Err(format!("YNAB authorization failed: {error}").into())),
+            )
+        } else if let Some(code) = param("code") {
+            (
+                "200 OK",
+                "Logged in, you can close this window.",
+                Some(Ok(code)),
+            )
+        } else {
+            ("400 Bad Request", "Missing authorization code.", None)
+        };
+
+        let response = format!(
+            "HTTP/1.1 {status}\r\nContent-Type: text/plain\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{message}",
+            message.len()
+        );
+        stream.write_all(response.as_bytes()).await?;
+
+        if let Some(result) = result {
+            return result;
+        }
+    }
+}
+
+/// Returns a wallet setting of a user, e.g. `USER_ALICE_EVM_WALLETS` for
+/// `EVM_WALLETS`, or the unprefixed setting for the budget of
+/// `YNAB_ACCESS_TOKEN`. Users only see their own wallets.
+pub fn user_var(name: &str, user: Option<&str>) -> Option<String> {
+    let name = match user {
+        Some(user) => format!(
+            "USER_{}_{name}",
+            user.to_uppercase()
+                .replace(|c: char| !c.is_ascii_alphanumeric(), "_")
+        ),
+        None => name.to_string(),
+    };
+
+    env::var(name).ok().filter(|v| !v.is_empty())
+}
+
+/// Returns the path a user's copy of a per-deployment file is stored at, e.g.
+/// `history-alice.sqlite` for `history.sqlite`.
+pub fn user_path(path: &str, user: Option<&str>) -> String {
+    let Some(user) = user else {
+        return path.to_string();
+    };
+
+    let path = Path::new(path);
+    let stem = path
+        .file_stem()
+        .map(|s| s.to_string_lossy())
+        .unwrap_or_default();
+    let file = match path.extension() {
+        Some(ext) => format!("{stem}-{user}.{}", ext.to_string_lossy()),
+        None => format!("{stem}-{user}"),
+    };
+
+    path.with_file_name(file).to_string_lossy().into_owned()
+}
@@ -0,0 +1,220 @@
+use crate::history::{History, Holding, PortfolioTotal};
+use chrono::{DateTime, Duration, Utc};
+use std::collections::BTreeMap;
+use std::error::Error;
+
+const PERIODS: [(&str, i64); 3] = [("Daily", 1), ("Weekly", 7), ("Monthly", 30)];
+
+/// Change of the portfolio value over a period, in the budget currency.
+#[derive(Debug, Clone)]
+pub struct Change {
+    pub absolute: f64,
+    pub percent: f64,
+}
+
+#[derive(Debug, Clone)]
+pub struct Performance {
+    pub latest: PortfolioTotal,
+    pub changes: Vec<(&'static str, Option<Change>)>,
+    pub all_time_high: PortfolioTotal,
+    /// Percentage below the all-time high, as a non-positive number.
+    pub drawdown: f64,
+}
+
+impl Performance {
+    /// Computes the performance of a portfolio from its totals, oldest first.
+    pub fn from_totals(totals: &[PortfolioTotal]) -> Option<Self> {
+        let latest = totals.last()?.clone();
+
+        let all_time_high = totals
+            .iter()
+            .max_by(|a, b| a.value.total_cmp(&b.value))?
+            .clone();
+
+        let changes = PERIODS
+            .iter()
+            .map(|(name, days)| {
+                let change =
+                    value_at(totals, latest.at - Duration::days(*days)).map(|past| Change {
+                        absolute: latest.value - past,
+                        percent: percent_change(past, latest.value),
+                    });
+                (*name, change)
+            })
+            .collect();
+
+        let drawdown = percent_change(all_time_high.value, latest.value).min(0.0);
+
+        Some(Performance {
+            latest,
+            changes,
+            all_time_high,
+            drawdown,
+        })
+    }
+}
+
+/// The latest recorded value at or before `at`.
+fn value_at(totals: &[PortfolioTotal], at: DateTime<Utc>) -> Option<f64> {
+    totals.iter().rev().find(|t| t.at <= at).map(|t| t.value)
+}
+
+fn percent_change(from: f64, to: f64) -> f64 {
+    if from == 0.0 {
+        return 0.0;
+    }
+
+    (to - from) / from * 100.0
+}
+
+/// Groups holdings by `key` and returns their totals, largest first.
+fn allocation(holdings: &[Holding], key: impl Fn(&Holding) -> String) -> Vec<(String, f64)> {
+    let mut groups: BTreeMap<String, f64> = BTreeMap::new();
+
+    for holding in holdings {
+        *groups.entry(key(holding)).or_default() += holding.value;
+    }
+
+    let mut groups: Vec<_> = groups.into_iter().collect();
+    groups.sort_by(|a, b| b.1.total_cmp(&a.1));
+    groups
+}
+
+fn print_allocation(title: &str, groups: &[(String, f64)], total: f64) {
+    println!("\n{title}");
+    for (name, value) in groups {
+        let share = if total == 0.0 {
+            0.0
+        } else {
+            value / total * 100.0
+        };
+        println!("  {name:<24} {share:>7.2}%  {value:>14.2}");
+    }
+}
+
+/// Prints the performance of the portfolio recorded in the history database,
+/// or in a user's own one.
+pub fn print_report(user: Option<&str>) -> Result<(), Box<dyn Error>> {
+    let history = History::open(user)?;
+
+    let totals = history.portfolio_totals()?;
+
+    let Some(performance) = Performance::from_totals(&totals) else {
+        println!("No history recorded yet. Run a sync first.");
+        return Ok(());
+    };
+
+    let currency = performance.latest.currency.clone().unwrap_or_default();
+
+    println!(
+        "Portfolio value: {:.2} {} ({})",
+        performance.latest.value,
+        currency,
+        performance.latest.at.format("%Y-%m-%d %H:%M UTC")
+    );
+
+    println!("\nChange");
+    for (name, change) in &performance.changes {
+        match change {
+            Some(change) => println!(
+                "  {name:<10} {:>+14.2} ({:+.2}%)",
+                change.absolute, change.percent
+            ),
+            None => println!("  {name:<10} {:>14}", "n/a"),
+        }
+    }
+
+    println!(
+        "\nAll-time high: {:.2} {} ({})",
+        performance.all_time_high.value,
+        currency,
+        performance.all_time_high.at.format("%Y-%m-%d")
+    );
+    println!("Drawdown:      {:.2}%", performance.drawdown);
+
+    let wallets = history.latest_wallets()?;
+    let total: f64 = wallets.iter().map(|w| w.value).sum();
+
+    print_allocation(
+        "Allocation by source",
+        &allocation(&wallets, |w| w.source.clone()),
+        total,
+    );
+
+    let mut assets = allocation(&history.latest_assets()?, |a| a.name.clone());
+
+    // sources that only report a total have no per-asset breakdown
+    let unclassified = total - assets.iter().map(|(_, v)| v).sum::<f64>();
+    if unclassified > 0.005 {
+        assets.push(("Unclassified".to_string(), unclassified));
+    }
+
+    print_allocation("Allocation by asset", &assets, total);
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn totals(values: &[f64]) -> Vec<PortfolioTotal> {
+        let start = Utc.with_ymd_and_hms(2026, 1, 1, 0, 0, 0).unwrap();
+
+        values
+            .iter()
+            .enumerate()
+            .map(|(day, value)| PortfolioTotal {
+                at: start + Duration::days(day as i64),
+                currency: Some("EUR".to_string()),
+                value: *value,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn computes_the_percent_change() {
+        assert_eq!(percent_change(100.0, 110.0), 10.0);
+        assert_eq!(percent_change(200.0, 150.0), -25.0);
+        assert_eq!(percent_change(0.0, 150.0), 0.0);
+    }
+
+    #[test]
+    fn computes_changes_all_time_high_and_drawdown() {
+        let mut values = vec![100.0; 30];
+        values.extend([200.0, 180.0, 150.0]);
+
+        let performance = Performance::from_totals(&totals(&values)).unwrap();
+
+        assert_eq!(performance.latest.value, 150.0);
+        assert_eq!(performance.all_time_high.value, 200.0);
+        assert_eq!(performance.drawdown, -25.0);
+
+        let change = |period| {
+            performance
+                .changes
+                .iter()
+                .find(|(name, _)| *name == period)
+                .and_then(|(_, change)| change.clone())
+                .map(|c| (c.absolute, c.percent))
+        };
+        assert_eq!(change("Daily"), Some((-30.0, -30.0 / 180.0 * 100.0)));
+        assert_eq!(change("Weekly"), Some((50.0, 50.0)));
+        assert_eq!(change("Monthly"), Some((50.0, 50.0)));
+    }
+
+    #[test]
+    fn has_no_drawdown_at_a_new_high_and_no_change_without_history() {
+        let performance = Performance::from_totals(&totals(&[100.0, 120.0])).unwrap();
+
+        assert_eq!(performance.drawdown, 0.0);
+        assert_eq!(performance.all_time_high.value, 120.0);
+        assert!(performance
+            .changes
+            .iter()
+            .filter(|(name, _)| *name != "Daily")
+            .all(|(_, change)| change.is_none()));
+        assert!(Performance::from_totals(&[]).is_none());
+    }
+}
@@ -0,0 +1,146 @@
+use crate::http;
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::env;
+use std::error::Error;
+use std::sync::{LazyLock, Mutex};
+use std::time::{Duration, Instant};
+use tracing::warn;
+
+const COINLORE_API_URL: &str = "https://api.coinlore.net/api";
+const COINLORE_PAGE_SIZE: usize = 100;
+/// Coins ranked below this are not looked up, which also keeps lookalike
+/// tokens from being priced as the coin they imitate.
+const COINLORE_MAX_RANK: usize = 1000;
+const CACHE_TTL: Duration = Duration::from_secs(300);
+
+/// USD stablecoins valued at their peg.
+const USD_STABLECOINS: [&str; 7] = ["USDC", "USDT", "DAI", "USDP", "TUSD", "FDUSD", "PYUSD"];
+
+#[derive(Debug, Deserialize)]
+struct CoinloreTickersResponse {
+    data: Vec<CoinloreTicker>,
+}
+
+#[derive(Debug, Deserialize)]
+struct CoinloreTicker {
+    symbol: String,
+    price_usd: String,
+}
+
+/// The Coinlore API at `COINLORE_API_URL`.
+pub fn coinlore_url() -> String {
+    env::var("COINLORE_API_URL")
+        .ok()
+        .filter(|u| !u.is_empty())
+        .map(|u| u.trim_end_matches('/').to_string())
+        .unwrap_or_else(|| COINLORE_API_URL.to_string())
+}
+
+/// Prices by symbol from the latest Coinlore pages fetched, so wallets valued
+/// in the same run do not page through the ranking again.
+struct PriceCache {
+    fetched_at: Instant,
+    pages: usize,
+    prices: HashMap<String, f64>,
+}
+
+static CACHE: LazyLock<Mutex<Option<PriceCache>>> = LazyLock::new(|| Mutex::new(None));
+
+/// Returns the USD price of a coin by its symbol, e.g. `ALGO`, or `None` when
+/// it is not among the top ranked coins. Symbols shared by several coins
+/// resolve to the one with the largest market cap.
+pub async fn get_price_usd(symbol: &str) -> Result<Option<f64>, Box<dyn Error + Send + Sync>> {
+    let symbol = symbol.to_uppercase();
+
+    if USD_STABLECOINS.contains(&symbol.as_str()) {
+        return Ok(Some(1.0));
+    }
+
+    get_market_price_usd(&symbol).await
+}
+
+/// Returns the price of a USD stablecoin: its peg, or its market price when
+/// that is more than `depeg_percent` away from the peg.
+pub async fn get_stablecoin_price_usd(
+    symbol: &str,
+    depeg_percent: Option<f64>,
+) -> Result<f64, Box<dyn Error + Send + Sync>> {
+    let Some(threshold) = depeg_percent else {
+        return Ok(1.0);
+    };
+
+    match get_market_price_usd(&symbol.to_uppercase()).await? {
+        Some(price) if ((price - 1.0) * 100.0).abs() > threshold => {
+            warn!("{symbol} is trading at {price} USD, off its peg by more than {threshold}%");
+            Ok(price)
+        }
+        _ => Ok(1.0),
+    }
+}
+
+/// Looks up an uppercase symbol in the Coinlore ranking, ignoring pegs.
+async fn get_market_price_usd(symbol: &str) -> Result<Option<f64>, Box<dyn Error + Send + Sync>> {
+    loop {
+        let pages = {
+            let mut cache = CACHE.lock().unwrap();
+
+            if cache
+                .as_ref()
+                .is_some_and(|c| c.fetched_at.elapsed() > CACHE_TTL)
+            {
+                *cache = None;
+            }
+
+            let cache = cache.get_or_insert_with(|| PriceCache {
+                fetched_at: Instant::now(),
+                pages: 0,
+                prices: HashMap::new(),
+            });
+
+            if let Some(price) = cache.prices.get(symbol) {
+                return Ok(Some(*price));
+            }
+
+            if cache.pages * COINLORE_PAGE_SIZE >= COINLORE_MAX_RANK {
+                return Ok(None);
+            }
+
+            cache.pages
+        };
+
+        let page = http::client()
+            .get(format!("{}/tickers/", coinlore_url()))
+            .query(&[
+                ("start", pages * COINLORE_PAGE_SIZE),
+                ("limit", COINLORE_PAGE_SIZE),
+            ])
+            .send()
+            .await?
+            .error_for_status()?
+            .json::<CoinloreTickersResponse>()
+            .await?;
+
+        let mut cache = CACHE.lock().unwrap();
+        let Some(cache) = cache.as_mut().filter(|c| c.pages == pages) else {
+            // another lookup fetched this page in the meantime
+            continue;
+        };
+
+        // a short page is the end of the ranking
+        cache.pages = if page.data.len() < COINLORE_PAGE_SIZE {
+            COINLORE_MAX_RANK / COINLORE_PAGE_SIZE
+        } else {
+            cache.pages + 1
+        };
+
+        for ticker in page.data {
+            if let Ok(price) = ticker.price_usd.parse() {
+                cache
+                    .prices
+                    .entry(ticker.symbol.to_uppercase())
+                    .or_insert(price);
+            }
+        }
+    }
+}
@@ -0,0 +1,148 @@
+use crate::oauth;
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use std::env;
+use std::error::Error;
+use tracing::info;
+
+/// A single asset held in a wallet, valued in USD.
+#[derive(Debug, Clone, Serialize)]
+pub struct AssetValue {
+    pub symbol: String,
+    pub quantity: f64,
+    pub price_usd: f64,
+    pub value_usd: f64,
+}
+
+impl AssetValue {
+    pub fn new(symbol: &str, quantity: f64, price_usd: f64) -> Self {
+        AssetValue {
+            symbol: symbol.to_string(),
+            quantity,
+            price_usd,
+            value_usd: quantity * price_usd,
+        }
+    }
+}
+
+/// The value of a wallet as returned by a source. Sources that only expose a
+/// total (e.g. scraped dashboards) leave `assets` empty.
+#[derive(Debug, Clone, Default)]
+pub struct WalletValue {
+    pub total_usd: f64,
+    pub assets: Vec<AssetValue>,
+    /// Symbols held in the wallet that could not be priced and are left out of
+    /// `total_usd`.
+    pub unpriced: Vec<String>,
+}
+
+impl WalletValue {
+    pub fn from_total(total_usd: f64) -> Self {
+        WalletValue {
+            total_usd,
+            assets: vec![],
+            unpriced: vec![],
+        }
+    }
+
+    pub fn from_assets(assets: Vec<AssetValue>) -> Self {
+        WalletValue {
+            total_usd: assets.iter().map(|a| a.value_usd).sum(),
+            assets,
+            unpriced: vec![],
+        }
+    }
+
+    pub fn with_unpriced(mut self, unpriced: Vec<String>) -> Self {
+        self.unpriced = unpriced;
+        self
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct WalletReport {
+    pub source: String,
+    pub wallet: String,
+    pub assets: Vec<AssetValue>,
+    pub value_usd: Option<f64>,
+    pub value_fiat: Option<f64>,
+    /// The YNAB transaction created or updated for this wallet.
+    pub transaction_id: Option<String>,
+    /// The adjustment written to YNAB, in milliunits of the budget currency.
+    pub delta: Option<i64>,
+    /// Why the value failed the sanity checks, if it did.
+    pub suspicious: Option<String>,
+    /// Whether the value was kept out of YNAB because it looked suspicious.
+    pub quarantined: bool,
+    pub error: Option<String>,
+}
+
+impl WalletReport {
+    pub fn new(source: &str, wallet: &str) -> Self {
+        WalletReport {
+            source: source.to_string(),
+            wallet: wallet.to_string(),
+            assets: vec![],
+            value_usd: None,
+            value_fiat: None,
+            transaction_id: None,
+            delta: None,
+            suspicious: None,
+            quarantined: false,
+            error: None,
+        }
+    }
+}
+
+/// Machine-readable summary of a sync run.
+#[derive(Debug, Serialize)]
+pub struct RunReport {
+    /// The user logged in with OAuth whose budget was synced.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user: Option<String>,
+    pub started_at: DateTime<Utc>,
+    pub finished_at: Option<DateTime<Utc>>,
+    pub budget_id: Option<String>,
+    pub account_id: Option<String>,
+    pub currency: Option<String>,
+    /// USD value of one unit of the budget currency.
+    pub fx_rate: Option<f64>,
+    pub wallets: Vec<WalletReport>,
+    pub errors: Vec<String>,
+}
+
+impl RunReport {
+    pub fn new(user: Option<&str>) -> Self {
+        RunReport {
+            user: user.map(str::to_string),
+            started_at: Utc::now(),
+            finished_at: None,
+            budget_id: None,
+            account_id: None,
+            currency: None,
+            fx_rate: None,
+            wallets: vec![],
+            errors: vec![],
+        }
+    }
+
+    /// Writes the report to `REPORT_PATH`, or the user's own copy of it,
+    /// and/or stdout if `REPORT_STDOUT` is set to `true`.
+    pub fn publish(&mut self) -> Result<(), Box<dyn Error>> {
+        self.finished_at = Some(Utc::now());
+
+        let json = serde_json::to_string_pretty(self)?;
+
+        if let Some(path) = env::var("REPORT_PATH").ok().filter(|p| !p.is_empty()) {
+            let path = oauth::user_path(&path, self.user.as_deref());
+            std::fs::write(&path, &json)?;
+            info!("Run report written to {path}");
+        }
+
+        if env::var("REPORT_STDOUT").is_ok_and(|v| v == "true") {
+            println!("{json}");
+        }
+
+        Ok(())
+    }
+}
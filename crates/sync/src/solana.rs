@@ -1,3 +1,4 @@
+use crate::report::WalletValue;
 use std::error::Error;
 use std::time::Duration;
 use tokio::time::sleep;
@@ -5,7 +6,9 @@ use tracing::info;
 
 const TIMEOUT_SECONDS: u64 = 30;
 
-pub async fn get_solana_wallet_net_worth(wallet: &str) -> Result<Option<f64>, Box<dyn Error>> {
+pub async fn get_solana_wallet_net_worth(
+    wallet: &str,
+) -> Result<Option<WalletValue>, Box<dyn Error>> {
     #[cfg(feature = "headless")]
     return get_net_worth_from_step_finance_with_headless_chrome(wallet).await;
 
@@ -16,7 +19,7 @@ pub async fn get_solana_wallet_net_worth(wallet: &str) -> Result<Option<f64>, Bo
 #[cfg(feature = "headless")]
 pub async fn get_net_worth_from_step_finance_with_headless_chrome(
     wallet: &str,
-) -> Result<Option<f64>, Box<dyn Error>> {
+) -> Result<Option<WalletValue>, Box<dyn Error>> {
     use headless_chrome::{Browser, LaunchOptions};
     let browser = Browser::new(
         LaunchOptions::default_builder()
@@ -49,13 +52,13 @@ pub async fn get_net_worth_from_step_finance_with_headless_chrome(
             .ok()
     });
 
-    Ok(first_line)
+    Ok(first_line.map(WalletValue::from_total))
 }
 
 #[cfg(feature = "docker")]
 pub async fn get_net_worth_from_step_finance_with_fantoccini(
     wallet: &str,
-) -> Result<Option<f64>, Box<dyn Error>> {
+) -> Result<Option<WalletValue>, Box<dyn Error>> {
     use fantoccini::wd::Capabilities;
     use fantoccini::{ClientBuilder, Locator};
 
@@ -98,5 +101,5 @@ pub async fn get_net_worth_from_step_finance_with_fantoccini(
 
     c.close().await?;
 
-    Ok(first_line)
+    Ok(first_line.map(WalletValue::from_total))
 }
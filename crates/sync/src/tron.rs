@@ -0,0 +1,163 @@
+use crate::http;
+use crate::prices::{get_price_usd, get_stablecoin_price_usd};
+use crate::report::{AssetValue, WalletValue};
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::env;
+use std::error::Error;
+use tracing::warn;
+
+const TRONGRID_API_URL: &str = "https://api.trongrid.io";
+const ADDRESS_PREFIX: u8 = 0x41;
+const SUN_PER_TRX: f64 = 1_000_000.0;
+
+/// TRC-20 stablecoins valued by default, by contract address.
+const TRC20_TOKENS: [(&str, &str, i32); 2] = [
+    ("TR7NHqjeKQxGTCi8q8ZY4pL8otSzgjLj6t", "USDT", 6),
+    ("TEkxiTehnzSmSe2XqrBj4w32RUN966rdz8", "USDC", 6),
+];
+
+/// Checks a base58check `T…` address.
+pub fn is_valid_address(address: &str) -> bool {
+    ::bitcoin::base58::decode_check(address)
+        .is_ok_and(|payload| payload.len() == 21 && payload[0] == ADDRESS_PREFIX)
+}
+
+#[derive(Debug, Deserialize)]
+struct AccountsResponse {
+    data: Vec<Account>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Account {
+    /// Liquid balance in sun, missing when zero.
+    #[serde(default)]
+    balance: i64,
+    /// TRX staked for bandwidth or energy.
+    #[serde(default, rename = "frozenV2")]
+    frozen: Vec<Frozen>,
+    /// TRX being unstaked.
+    #[serde(default, rename = "unfrozenV2")]
+    unfrozen: Vec<Unfrozen>,
+    /// Token balances as single-entry maps of contract address to amount.
+    #[serde(default)]
+    trc20: Vec<HashMap<String, String>>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Frozen {
+    #[serde(default)]
+    amount: i64,
+}
+
+#[derive(Debug, Deserialize)]
+struct Unfrozen {
+    #[serde(default)]
+    unfreeze_amount: i64,
+}
+
+pub async fn get_tron_wallet_value(
+    wallet: &str,
+) -> Result<Option<WalletValue>, Box<dyn Error + Send + Sync>> {
+    let url = env::var("TRON_API_URL")
+        .ok()
+        .filter(|u| !u.is_empty())
+        .unwrap_or_else(|| TRONGRID_API_URL.to_string());
+
+    let mut request = http::client().get(format!(
+        "{}/v1/accounts/{wallet}",
+        url.trim_end_matches('/')
+    ));
+
+    if let Some(key) = env::var("TRON_API_KEY").ok().filter(|k| !k.is_empty()) {
+        request = request.header("TRON-PRO-API-KEY", key);
+    }
+
+    let response = request
+        .send()
+        .await?
+        .error_for_status()?
+        .json::<AccountsResponse>()
+        .await?;
+
+    // accounts that never received TRX are not found
+    let Some(account) = response.data.into_iter().next() else {
+        return Ok(Some(WalletValue::from_assets(vec![])));
+    };
+
+    let depeg_percent = env::var("TRON_DEPEG_PERCENT")
+        .ok()
+        .and_then(|p| p.parse().ok());
+
+    let mut assets = vec![];
+    let mut unpriced = vec![];
+
+    let sun = account.balance
+        + account.frozen.iter().map(|f| f.amount).sum::<i64>()
+        + account
+            .unfrozen
+            .iter()
+            .map(|u| u.unfreeze_amount)
+            .sum::<i64>();
+
+    if sun > 0 {
+        match get_price_usd("TRX").await? {
+            Some(price) => assets.push(AssetValue::new("TRX", sun as f64 / SUN_PER_TRX, price)),
+            None => {
+                warn!("No price found for TRX");
+                unpriced.push("TRX".to_string());
+            }
+        }
+    }
+
+    for (contract, amount) in account.trc20.iter().flatten() {
+        let Some((_, symbol, decimals)) = TRC20_TOKENS.iter().find(|(c, _, _)| c == contract)
+        else {
+            continue;
+        };
+
+        let quantity = amount.parse::<f64>()? / 10f64.powi(*decimals);
+
+        if quantity > 0.0 {
+            let price = get_stablecoin_price_usd(symbol, depeg_percent).await?;
+            assets.push(AssetValue::new(symbol, quantity, price));
+        }
+    }
+
+    Ok(Some(
+        WalletValue::from_assets(assets).with_unpriced(unpriced),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn accepts_addresses_with_a_valid_checksum() {
+        for address in [
+            "TR7NHqjeKQxGTCi8q8ZY4pL8otSzgjLj6t",
+            "TEkxiTehnzSmSe2XqrBj4w32RUN966rdz8",
+            "T9yD14Nj9j7xAB4dbGeiX9h8unkKHxuWwb",
+        ] {
+            assert!(is_valid_address(address), "{address} should be valid");
+        }
+    }
+
+    #[test]
+    fn rejects_bad_checksums_and_other_prefixes() {
+        for address in [
+            // one character off
+            "TR7NHqjeKQxGTCi8q8ZY4pL8otSzgjLj6u",
+            "TR7NHqjeKQxGTCi8q8ZY4pL9otSzgjLj6t",
+            // a bitcoin address, without the 0x41 prefix
+            "1A1zP1eP5QGefi2DMPTfTL5SLmv7DivfNa",
+            // truncated, and an ethereum style address
+            "TR7NHqjeKQxGTCi8q8ZY4pL8otSzgjLj6",
+            "0xdAC17F958D2ee523a2206206994597C13D831ec7",
+            "",
+        ] {
+            assert!(!is_valid_address(address), "{address} should be invalid");
+        }
+    }
+}
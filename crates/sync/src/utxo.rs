@@ -0,0 +1,398 @@
+use crate::http;
+use crate::oauth;
+use crate::prices::get_price_usd;
+use crate::report::{AssetValue, WalletValue};
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::env;
+use std::error::Error;
+
+const BECH32_CHARSET: &str = "qpzry9x8gf2tvdw0s3jn54khce6mua7l";
+
+/// API serving a network's address balances.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Backend {
+    /// Esplora (Blockstream, mempool.space and forks).
+    Esplora,
+    /// Trezor Blockbook.
+    Blockbook,
+}
+
+/// A Bitcoin-like UTXO chain.
+#[derive(Debug)]
+pub struct Network {
+    /// Source name shown in reports.
+    pub name: &'static str,
+    /// Ticker, also the prefix of the network's environment variables.
+    pub symbol: &'static str,
+    /// Version bytes of base58 P2PKH and P2SH addresses.
+    base58_versions: &'static [u8],
+    /// Human-readable part of segwit addresses.
+    segwit_hrp: Option<&'static str>,
+    /// Prefix of CashAddr addresses.
+    cashaddr_prefix: Option<&'static str>,
+    backend: Backend,
+    api_url: &'static str,
+}
+
+pub const NETWORKS: [Network; 4] = [
+    Network {
+        name: "Bitcoin",
+        symbol: "BTC",
+        base58_versions: &[0x00, 0x05],
+        segwit_hrp: Some("bc"),
+        cashaddr_prefix: None,
+        backend: Backend::Esplora,
+        api_url: "https://blockstream.info/api",
+    },
+    Network {
+        name: "Litecoin",
+        symbol: "LTC",
+        base58_versions: &[0x30, 0x32, 0x05],
+        segwit_hrp: Some("ltc"),
+        cashaddr_prefix: None,
+        backend: Backend::Esplora,
+        api_url: "https://litecoinspace.org/api",
+    },
+    Network {
+        name: "Dogecoin",
+        symbol: "DOGE",
+        base58_versions: &[0x1e, 0x16],
+        segwit_hrp: None,
+        cashaddr_prefix: None,
+        backend: Backend::Blockbook,
+        api_url: "https://doge1.trezor.io",
+    },
+    Network {
+        name: "Bitcoin Cash",
+        symbol: "BCH",
+        base58_versions: &[0x00, 0x05],
+        segwit_hrp: None,
+        cashaddr_prefix: Some("bitcoincash"),
+        backend: Backend::Blockbook,
+        api_url: "https://bch1.trezor.io",
+    },
+];
+
+/// An address and the network it is configured on.
+pub type Wallet = (&'static Network, String);
+
+impl Network {
+    /// Returns a user's wallets listed in `{SYMBOL}_WALLETS` that are valid
+    /// addresses on this network.
+    pub fn wallets(&self, user: Option<&str>) -> Vec<String> {
+        oauth::user_var(&format!("{}_WALLETS", self.symbol), user)
+            .unwrap_or_default()
+            .split(',')
+            .filter(|w| self.is_valid_address(w))
+            .map(ToString::to_string)
+            .collect()
+    }
+
+    pub fn is_valid_address(&self, address: &str) -> bool {
+        if let Ok(payload) = ::bitcoin::base58::decode_check(address) {
+            return payload.len() == 21 && self.base58_versions.contains(&payload[0]);
+        }
+
+        if let Some(hrp) = self.segwit_hrp {
+            if let Ok((decoded, _, _)) = bech32::segwit::decode(address) {
+                return decoded.as_str() == hrp;
+            }
+        }
+
+        match self.cashaddr_prefix {
+            Some(prefix) => is_valid_cashaddr(address, prefix),
+            None => false,
+        }
+    }
+
+    /// Returns the backend and its URL, overridden by `{SYMBOL}_BACKEND` and
+    /// `{SYMBOL}_API_URL`.
+    fn backend(&self) -> (Backend, String) {
+        let backend = match env::var(format!("{}_BACKEND", self.symbol)).as_deref() {
+            Ok("esplora") => Backend::Esplora,
+            Ok("blockbook") => Backend::Blockbook,
+            _ => self.backend,
+        };
+
+        let url = env::var(format!("{}_API_URL", self.symbol))
+            .ok()
+            .filter(|u| !u.is_empty())
+            .unwrap_or_else(|| self.api_url.to_string());
+
+        (backend, url.trim_end_matches('/').to_string())
+    }
+}
+
+/// Returns the wallets of every network, and apart from them the addresses
+/// configured on several networks, e.g. a legacy address valid on both Bitcoin
+/// and Bitcoin Cash. Wallets are YNAB payees by address, so the networks would
+/// overwrite each other's adjustments.
+pub fn configured_wallets(user: Option<&str>) -> (Vec<Wallet>, Vec<Wallet>) {
+    let wallets = NETWORKS
+        .iter()
+        .flat_map(|n| n.wallets(user).into_iter().map(move |w| (n, w)))
+        .collect::<Vec<_>>();
+
+    let mut counts: HashMap<String, usize> = HashMap::new();
+    for (_, wallet) in &wallets {
+        *counts.entry(wallet.clone()).or_default() += 1;
+    }
+
+    wallets
+        .into_iter()
+        .partition(|(_, wallet)| counts[wallet] == 1)
+}
+
+/// Checks a CashAddr address, with or without its prefix.
+fn is_valid_cashaddr(address: &str, prefix: &str) -> bool {
+    if address.chars().any(|c| c.is_ascii_uppercase())
+        && address.chars().any(|c| c.is_ascii_lowercase())
+    {
+        return false;
+    }
+
+    let address = address.to_lowercase();
+    let payload = match address.split_once(':') {
+        Some((p, payload)) if p == prefix => payload,
+        Some(_) => return false,
+        None => address.as_str(),
+    };
+
+    // a 160 bit hash, its version byte and the 40 bit checksum
+    if payload.len() != 42 || !(payload.starts_with('q') || payload.starts_with('p')) {
+        return false;
+    }
+
+    let Some(values) = payload
+        .chars()
+        .map(|c| BECH32_CHARSET.find(c).map(|v| v as u64))
+        .collect::<Option<Vec<_>>>()
+    else {
+        return false;
+    };
+
+    let data = prefix
+        .bytes()
+        .map(|b| (b & 0x1f) as u64)
+        .chain([0])
+        .chain(values);
+
+    cashaddr_polymod(data) == 0
+}
+
+fn cashaddr_polymod(data: impl Iterator<Item = u64>) -> u64 {
+    const GENERATORS: [u64; 5] = [
+        0x98f2bc8e61,
+        0x79b76d99e2,
+        0xf33e5fb3c4,
+        0xae2eabe2a8,
+        0x1e4f43e470,
+    ];
+
+    let mut c = 1u64;
+
+    for d in data {
+        let c0 = c >> 35;
+        c = ((c & 0x07ffffffff) << 5) ^ d;
+
+        for (i, generator) in GENERATORS.iter().enumerate() {
+            if c0 & (1 << i) != 0 {
+                c ^= generator;
+            }
+        }
+    }
+
+    c ^ 1
+}
+
+#[derive(Debug, Deserialize)]
+struct EsploraStats {
+    funded_txo_sum: u64,
+    spent_txo_sum: u64,
+}
+
+#[derive(Debug, Deserialize)]
+struct EsploraAddress {
+    chain_stats: EsploraStats,
+}
+
+#[derive(Debug, Deserialize)]
+struct BlockbookAddress {
+    balance: String,
+}
+
+/// Returns the confirmed balance of an address, in its smallest unit.
+async fn get_balance(network: &Network, wallet: &str) -> Result<u64, Box<dyn Error + Send + Sync>> {
+    let client = http::client();
+
+    match network.backend() {
+        (Backend::Esplora, url) => {
+            let address = client
+                .get(format!("{url}/address/{wallet}"))
+                .send()
+                .await?
+                .error_for_status()?
+                .json::<EsploraAddress>()
+                .await?;
+
+            Ok(address.chain_stats.funded_txo_sum - address.chain_stats.spent_txo_sum)
+        }
+        (Backend::Blockbook, url) => {
+            let address = client
+                .get(format!("{url}/api/v2/address/{wallet}"))
+                .query(&[("details", "basic")])
+                .send()
+                .await?
+                .error_for_status()?
+                .json::<BlockbookAddress>()
+                .await?;
+
+            Ok(address.balance.parse()?)
+        }
+    }
+}
+
+pub async fn get_utxo_wallet_value(
+    network: &Network,
+    wallet: &str,
+) -> Result<Option<WalletValue>, Box<dyn Error + Send + Sync>> {
+    let balance = get_balance(network, wallet).await?;
+
+    let price = get_price_usd(network.symbol)
+        .await?
+        .ok_or_else(|| format!("No price found for {}", network.symbol))?;
+
+    Ok(Some(WalletValue::from_assets(vec![AssetValue::new(
+        network.symbol,
+        balance as f64 / 100_000_000.0,
+        price,
+    )])))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const BITCOIN: &Network = &NETWORKS[0];
+    const LITECOIN: &Network = &NETWORKS[1];
+    const DOGECOIN: &Network = &NETWORKS[2];
+    const BITCOIN_CASH: &Network = &NETWORKS[3];
+
+    #[test]
+    fn accepts_the_address_formats_of_each_network() {
+        for (network, address) in [
+            (BITCOIN, "1A1zP1eP5QGefi2DMPTfTL5SLmv7DivfNa"),
+            (BITCOIN, "31h38a54tFMrR8kzBnP2241MFD2EUHtGha"),
+            (BITCOIN, "bc1qqqqsyqcyq5rqwzqfpg9scrgwpugpzysn4v0345"),
+            (LITECOIN, "LKDyUEtTR1HXamkiEphisSiBJu6o3ZPE34"),
+            (LITECOIN, "M7uBSTV2qNDHDe2tHfNMqhFkZucgRMpJQk"),
+            (LITECOIN, "ltc1qqqqsyqcyq5rqwzqfpg9scrgwpugpzysn3s44dy"),
+            (DOGECOIN, "D597kHXGdkwkryF9oGhz9Bp1ypTpD1u99Z"),
+            (DOGECOIN, "9rSHsR8xxKEkKW8Tbv3SGBdiwnQGWZ4bdM"),
+            (BITCOIN_CASH, "1BpEi6DfDAUFd7GtittLSdBeYJvcoaVggu"),
+            (
+                BITCOIN_CASH,
+                "bitcoincash:qpm2qsznhks23z7629mms6s4cwef74vcwvy22gdx6a",
+            ),
+            (BITCOIN_CASH, "qpm2qsznhks23z7629mms6s4cwef74vcwvy22gdx6a"),
+            (
+                BITCOIN_CASH,
+                "BITCOINCASH:QPM2QSZNHKS23Z7629MMS6S4CWEF74VCWVY22GDX6A",
+            ),
+        ] {
+            assert!(
+                network.is_valid_address(address),
+                "{address} should be a valid {} address",
+                network.name
+            );
+        }
+    }
+
+    #[test]
+    fn rejects_addresses_of_other_networks_and_bad_checksums() {
+        for (network, address) in [
+            // litecoin and dogecoin addresses
+            (BITCOIN, "LKDyUEtTR1HXamkiEphisSiBJu6o3ZPE34"),
+            (BITCOIN, "D597kHXGdkwkryF9oGhz9Bp1ypTpD1u99Z"),
+            // a testnet segwit address
+            (BITCOIN, "tb1qqqqsyqcyq5rqwzqfpg9scrgwpugpzysnl25zw8"),
+            (LITECOIN, "bc1qqqqsyqcyq5rqwzqfpg9scrgwpugpzysn4v0345"),
+            (DOGECOIN, "1A1zP1eP5QGefi2DMPTfTL5SLmv7DivfNa"),
+            (BITCOIN_CASH, "bc1qqqqsyqcyq5rqwzqfpg9scrgwpugpzysn4v0345"),
+            // one character off
+            (BITCOIN, "1A1zP1eP5QGefi2DMPTfTL5SLmv7DivfNb"),
+            (
+                BITCOIN_CASH,
+                "bitcoincash:qpm2qsznhks23z7629mms6s4cwef74vcwvy22gdx6b",
+            ),
+            // mixed case, another prefix and a truncated payload
+            (
+                BITCOIN_CASH,
+                "bitcoincash:Qpm2qsznhks23z7629mms6s4cwef74vcwvy22gdx6a",
+            ),
+            (
+                BITCOIN_CASH,
+                "bchtest:qpm2qsznhks23z7629mms6s4cwef74vcwvy22gdx6a",
+            ),
+            (
+                BITCOIN_CASH,
+                "bitcoincash:qpm2qsznhks23z7629mms6s4cwef74vcwvy22gdx",
+            ),
+            (BITCOIN, ""),
+        ] {
+            assert!(
+                !network.is_valid_address(address),
+                "{address} should not be a valid {} address",
+                network.name
+            );
+        }
+    }
+
+    /// Expands a CashAddr address as its checksum is computed over.
+    fn expand(prefix: &str, payload: &str) -> Vec<u64> {
+        prefix
+            .bytes()
+            .map(|b| (b & 0x1f) as u64)
+            .chain([0])
+            .chain(
+                payload
+                    .chars()
+                    .map(|c| BECH32_CHARSET.find(c).unwrap() as u64),
+            )
+            .collect()
+    }
+
+    #[test]
+    fn polymod_of_a_valid_cashaddr_is_zero() {
+        // test vectors of the CashAddr specification
+        for (prefix, payload) in [
+            ("prefix", "x64nx6hz"),
+            ("p", "gpf8m4h7"),
+            ("bitcoincash", "qpzry9x8gf2tvdw0s3jn54khce6mua7lcw20ayyn"),
+            ("bchtest", "testnetaddress4d6njnut"),
+            (
+                "bchreg",
+                "555555555555555555555555555555555555555555555udxmlmrz",
+            ),
+        ] {
+            assert_eq!(
+                cashaddr_polymod(expand(prefix, payload).into_iter()),
+                0,
+                "{prefix}:{payload}"
+            );
+        }
+    }
+
+    #[test]
+    fn polymod_detects_a_changed_character() {
+        assert_ne!(
+            cashaddr_polymod(expand("prefix", "x64nx6hq").into_iter()),
+            0
+        );
+        assert_ne!(
+            cashaddr_polymod(expand("bitcoincash", "x64nx6hz").into_iter()),
+            0
+        );
+    }
+}
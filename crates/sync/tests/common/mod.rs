@@ -0,0 +1,133 @@
+//! Helpers shared by the integration tests, which run the `sync` binary
+//! against a mock YNAB server and recorded third-party responses.
+
+// each test file uses its own subset of the helpers
+#![allow(dead_code)]
+
+use cassette::Cassette;
+use chrono::Utc;
+use serde_json::Value;
+use std::path::Path;
+use std::process::{Command, Output};
+use ynab_mock::MockYnab;
+
+/// Runs the `sync` binary in `dir` with nothing but the given environment,
+/// authorized with the mock's token and printing its run report to stdout.
+pub fn run_sync(mock: &MockYnab, dir: &Path, env: &[(&str, &str)]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_sync"))
+        .current_dir(dir)
+        .env_clear()
+        .env("YNAB_API_URL", mock.base_path())
+        .env("YNAB_ACCESS_TOKEN", mock.token())
+        .env("REPORT_STDOUT", "true")
+        .envs(env.iter().copied())
+        .output()
+        .expect("run the sync binary")
+}
+
+/// A legacy address, valid on both Bitcoin and Bitcoin Cash.
+pub const BITCOIN_ADDRESS: &str = "1A1zP1eP5QGefi2DMPTfTL5SLmv7DivfNa";
+
+/// A sync run against a mock YNAB server.
+pub struct Run {
+    pub mock: MockYnab,
+    pub output: Output,
+}
+
+impl Run {
+    /// Runs the `sync` binary in a temporary directory with the given
+    /// environment.
+    pub fn new(mock: MockYnab, env: &[(&str, &str)]) -> Self {
+        let dir = tempfile::tempdir().unwrap();
+        let output = run_sync(&mock, dir.path(), env);
+
+        Run { mock, output }
+    }
+
+    pub fn report(&self) -> Value {
+        report(&self.output)
+    }
+}
+
+/// Values [`BITCOIN_ADDRESS`] with Esplora and Coinlore replaying the given
+/// cassettes.
+pub fn run_bitcoin(esplora: &Cassette, coinlore: &Cassette) -> Run {
+    Run::new(
+        MockYnab::start(),
+        &[
+            ("BTC_WALLETS", BITCOIN_ADDRESS),
+            ("BTC_API_URL", &esplora.url()),
+            ("COINLORE_API_URL", &coinlore.url()),
+        ],
+    )
+}
+
+/// Values a Binance account with the given cassette.
+pub fn run_binance(binance: &Cassette) -> Run {
+    Run::new(
+        MockYnab::start(),
+        &[
+            ("BINANCE_API_KEY", "key"),
+            ("BINANCE_SECRET_KEY", "secret"),
+            ("BINANCE_API_URL", &binance.url()),
+        ],
+    )
+}
+
+/// Values 125 USDC of manual savings into a budget in `currency`, converted
+/// with Frankfurter replaying the given cassette.
+pub fn run_savings(currency: &str, frankfurter: &Cassette) -> Run {
+    let mock = MockYnab::start();
+    mock.set_currency(currency);
+
+    Run::new(
+        mock,
+        &[
+            ("FRANKFURTER_API_URL", &frankfurter.url()),
+            (
+                "MANUAL_ASSETS",
+                r#"[{"symbol": "USDC", "quantity": 125, "label": "Savings"}]"#,
+            ),
+        ],
+    )
+}
+
+pub fn assert_success(output: &Output) {
+    assert!(
+        output.status.success(),
+        "sync failed:\n{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+}
+
+/// The run report printed by a sync.
+pub fn report(output: &Output) -> Value {
+    serde_json::from_slice(&output.stdout).unwrap_or_else(|e| {
+        panic!(
+            "no run report ({e}) in:\n{}",
+            String::from_utf8_lossy(&output.stdout)
+        )
+    })
+}
+
+/// The report of a wallet in a run report.
+pub fn wallet<'a>(report: &'a Value, wallet: &str) -> &'a Value {
+    report["wallets"]
+        .as_array()
+        .and_then(|wallets| wallets.iter().find(|w| w["wallet"] == wallet))
+        .unwrap_or_else(|| panic!("no wallet {wallet} in the report:\n{report:#}"))
+}
+
+/// Replays the cassette `tests/cassettes/{name}.json`, recorded from
+/// `upstream`.
+pub fn cassette(name: &str, upstream: &str) -> Cassette {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/cassettes")
+        .join(format!("{name}.json"));
+
+    Cassette::start(path, upstream)
+}
+
+pub fn today() -> String {
+    Utc::now().date_naive().format("%Y-%m-%d").to_string()
+}
@@ -0,0 +1,274 @@
+//! Runs the `sync` binary against recorded responses of the APIs wallets are
+//! valued with. Set `RECORD_CASSETTES=true` to record them again.
+
+mod common;
+
+use common::{
+    assert_success, cassette, run_binance, run_bitcoin, run_savings, wallet, Run, BITCOIN_ADDRESS,
+};
+use ynab_mock::MockYnab;
+
+const ESPLORA: &str = "https://blockstream.info/api";
+const COINLORE: &str = "https://api.coinlore.net/api";
+const FRANKFURTER: &str = "https://api.frankfurter.app";
+const BINANCE: &str = "https://api.binance.com";
+const KRAKEN: &str = "https://api.kraken.com";
+const COSMOS_ADDRESS: &str = "cosmos1hsk6jryyqjfhp5dhc55tc9jtckygx0eph6dd02";
+const COSMOS_HUB: &str = "https://rest.cosmos.directory/cosmoshub";
+
+#[test]
+fn values_a_bitcoin_wallet_with_esplora_and_coinlore() {
+    let esplora = cassette("esplora_bitcoin", ESPLORA);
+    let coinlore = cassette("coinlore_bitcoin", COINLORE);
+
+    let run = run_bitcoin(&esplora, &coinlore);
+    assert_success(&run.output);
+
+    let report = run.report();
+    let bitcoin = wallet(&report, BITCOIN_ADDRESS);
+    assert_eq!(bitcoin["source"], "Bitcoin");
+    assert_eq!(bitcoin["assets"][0]["quantity"], 1.0);
+    assert_eq!(bitcoin["value_usd"], 67250.5);
+
+    let transactions = run.mock.transactions();
+    assert_eq!(transactions.len(), 1);
+    assert_eq!(transactions[0].amount, 67_250_500);
+    assert!(esplora.unplayed().is_empty() && coinlore.unplayed().is_empty());
+}
+
+#[test]
+fn reports_a_wallet_error_on_a_malformed_balance() {
+    let esplora = cassette("esplora_malformed", ESPLORA);
+    let coinlore = cassette("coinlore_bitcoin", COINLORE);
+
+    let run = run_bitcoin(&esplora, &coinlore);
+    assert_success(&run.output);
+
+    let report = run.report();
+    let bitcoin = wallet(&report, BITCOIN_ADDRESS);
+    assert!(bitcoin["value_usd"].is_null());
+    assert!(bitcoin["error"]
+        .as_str()
+        .is_some_and(|e| e.contains("decoding")));
+    assert!(run.mock.transactions().is_empty());
+}
+
+#[test]
+fn reports_a_wallet_error_when_rate_limited() {
+    let esplora = cassette("esplora_rate_limited", ESPLORA);
+    let coinlore = cassette("coinlore_bitcoin", COINLORE);
+
+    let run = run_bitcoin(&esplora, &coinlore);
+    assert_success(&run.output);
+
+    assert!(wallet(&run.report(), BITCOIN_ADDRESS)["error"]
+        .as_str()
+        .is_some_and(|e| e.contains("429")));
+    assert!(coinlore.requests().is_empty());
+    assert!(run.mock.transactions().is_empty());
+}
+
+#[test]
+fn reports_a_wallet_error_when_the_price_is_missing() {
+    let esplora = cassette("esplora_bitcoin", ESPLORA);
+    let coinlore = cassette("coinlore_empty", COINLORE);
+
+    let run = run_bitcoin(&esplora, &coinlore);
+    assert_success(&run.output);
+
+    assert!(wallet(&run.report(), BITCOIN_ADDRESS)["error"]
+        .as_str()
+        .is_some_and(|e| e.contains("No price found for BTC")));
+    assert!(run.mock.transactions().is_empty());
+}
+
+#[test]
+fn converts_values_to_the_budget_currency_with_frankfurter() {
+    let frankfurter = cassette("frankfurter_eur", FRANKFURTER);
+
+    let run = run_savings("EUR", &frankfurter);
+    assert_success(&run.output);
+
+    let report = run.report();
+    assert_eq!(report["fx_rate"], 1.25);
+    assert_eq!(wallet(&report, "Savings")["value_fiat"], 100.0);
+
+    let transactions = run.mock.transactions();
+    assert_eq!(transactions.len(), 1);
+    assert_eq!(transactions[0].amount, 100_000);
+}
+
+#[test]
+fn fails_without_changes_when_the_exchange_rate_is_unavailable() {
+    let frankfurter = cassette("frankfurter_unknown_currency", FRANKFURTER);
+
+    let run = run_savings("XYZ", &frankfurter);
+
+    assert!(!run.output.status.success());
+    assert!(String::from_utf8_lossy(&run.output.stderr).contains("ExchangeRate"));
+    assert!(run.report()["errors"][0]
+        .as_str()
+        .is_some_and(|e| e.contains("404")));
+    assert!(run.mock.transactions().is_empty());
+}
+
+#[test]
+fn values_every_binance_wallet() {
+    let binance = cassette("binance", BINANCE);
+
+    let run = run_binance(&binance);
+    assert_success(&run.output);
+
+    let report = run.report();
+    let binance_wallet = wallet(&report, "Binance");
+    let assets = binance_wallet["assets"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|a| {
+            (
+                a["symbol"].as_str().unwrap(),
+                a["quantity"].as_f64().unwrap(),
+            )
+        })
+        .collect::<Vec<_>>();
+    // the margin and futures wallets are only reported as their worth in BTC
+    assert_eq!(assets, vec![("BTC", 0.65), ("USDT", 1250.0)]);
+    assert_eq!(binance_wallet["value_usd"], 40250.0);
+
+    let transactions = run.mock.transactions();
+    assert_eq!(transactions.len(), 1);
+    assert_eq!(transactions[0].amount, 40_250_000);
+    assert!(binance.unplayed().is_empty());
+}
+
+#[test]
+fn reports_a_wallet_error_when_binance_rejects_the_key() {
+    let binance = cassette("binance_invalid_key", BINANCE);
+
+    let run = run_binance(&binance);
+    assert_success(&run.output);
+
+    assert!(wallet(&run.report(), "Binance")["error"]
+        .as_str()
+        .is_some_and(|e| e.contains("401")));
+    assert!(run.mock.transactions().is_empty());
+}
+
+#[test]
+fn values_kraken_balances_with_a_single_balance_request() {
+    let kraken = cassette("kraken", KRAKEN);
+
+    let run = Run::new(
+        MockYnab::start(),
+        &[
+            ("KRAKEN_API_KEY", "key"),
+            ("KRAKEN_API_SECRET", "c2VjcmV0"),
+            ("KRAKEN_API_URL", &kraken.url()),
+        ],
+    );
+    assert_success(&run.output);
+
+    let report = run.report();
+    let kraken_wallet = wallet(&report, "Kraken");
+    assert_eq!(kraken_wallet["value_usd"], 30750.0);
+    assert!(kraken_wallet["error"].is_null());
+
+    let balance_requests = kraken
+        .requests()
+        .iter()
+        .filter(|r| r.ends_with("/BalanceEx"))
+        .count();
+    assert_eq!(balance_requests, 1);
+    assert!(kraken.unplayed().is_empty());
+}
+
+#[test]
+fn skips_an_exchange_configured_without_its_secret() {
+    let run = Run::new(
+        MockYnab::start(),
+        &[
+            ("COINBASE_API_KEY_NAME", "organizations/1/apiKeys/2"),
+            ("KRAKEN_API_KEY", "key"),
+            ("BYBIT_API_KEY", "key"),
+            ("BINANCE_API_KEY", "key"),
+            (
+                "MANUAL_ASSETS",
+                r#"[{"symbol": "USDC", "quantity": 125, "label": "Savings"}]"#,
+            ),
+        ],
+    );
+    assert_success(&run.output);
+
+    let report = run.report();
+    assert_eq!(wallet(&report, "Savings")["value_usd"], 125.0);
+    assert_eq!(report["wallets"].as_array().unwrap().len(), 1);
+}
+
+#[test]
+fn values_a_cosmos_wallet_with_only_its_verified_ibc_denoms() {
+    let lcd = cassette("cosmos_hub", COSMOS_HUB);
+    let coinlore = cassette("coinlore_tickers", COINLORE);
+
+    let run = Run::new(
+        MockYnab::start(),
+        &[
+            ("COSMOS_WALLETS", COSMOS_ADDRESS),
+            (
+                "COSMOS_CHAINS",
+                &format!("cosmos|{}|uatom|6|ATOM", lcd.url()),
+            ),
+            ("COINLORE_API_URL", &coinlore.url()),
+        ],
+    );
+    assert_success(&run.output);
+
+    // the bank balance, the delegation and the pending rewards, and the USDC
+    // received from Noble. The USDC sent over an unknown channel and the denom
+    // without a trace are skipped.
+    let report = run.report();
+    let cosmos = wallet(&report, COSMOS_ADDRESS);
+    let assets = cosmos["assets"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|a| {
+            (
+                a["symbol"].as_str().unwrap(),
+                a["quantity"].as_f64().unwrap(),
+            )
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(assets, vec![("ATOM", 3.5), ("USDC", 5.0)]);
+    assert_eq!(cosmos["value_usd"], 33.0);
+    assert!(cosmos["error"].is_null());
+    assert!(lcd.unplayed().is_empty());
+}
+
+#[test]
+fn reports_an_address_configured_on_several_networks() {
+    let run = Run::new(
+        MockYnab::start(),
+        &[
+            ("BTC_WALLETS", BITCOIN_ADDRESS),
+            ("BCH_WALLETS", BITCOIN_ADDRESS),
+        ],
+    );
+    assert_success(&run.output);
+
+    let report = run.report();
+    let sources = report["wallets"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|w| {
+            assert!(w["value_usd"].is_null());
+            assert!(w["error"]
+                .as_str()
+                .is_some_and(|e| e.contains("several networks")));
+            w["source"].as_str().unwrap()
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(sources, vec!["Bitcoin", "Bitcoin Cash"]);
+    assert!(run.mock.transactions().is_empty());
+}
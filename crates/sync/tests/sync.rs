@@ -0,0 +1,196 @@
+//! Runs the `sync` binary against a mock YNAB server, valuing manual USD
+//! stablecoin holdings so that no other API is called.
+
+mod common;
+
+use chrono::{Duration, Utc};
+use common::{assert_success, run_sync, today};
+use ynab_api::models::TransactionClearedStatus;
+use ynab_mock::MockYnab;
+
+#[test]
+fn creates_the_account_and_a_transaction_per_wallet() {
+    let mock = MockYnab::start();
+    let dir = tempfile::tempdir().unwrap();
+
+    let output = run_sync(
+        &mock,
+        dir.path(),
+        &[(
+            "MANUAL_ASSETS",
+            r#"[
+            {"symbol": "USDC", "quantity": 1234.5, "label": "Savings"},
+            {"symbol": "DAI", "quantity": 100, "label": "Cold storage"}
+        ]"#,
+        )],
+    );
+    assert_success(&output);
+
+    let accounts = mock.accounts();
+    assert_eq!(accounts.len(), 1);
+    assert_eq!(accounts[0].name, "Crypto");
+
+    let mut transactions = mock.transactions();
+    transactions.sort_by_key(|t| t.amount);
+    let summary = transactions
+        .iter()
+        .map(|t| (t.payee_name.clone().flatten(), t.date.clone(), t.amount))
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        summary,
+        vec![
+            (Some("Cold storage".to_string()), today(), 100_000),
+            (Some("Savings".to_string()), today(), 1_234_500),
+        ]
+    );
+    assert!(transactions
+        .iter()
+        .all(|t| t.account_id == accounts[0].id && t.cleared == TransactionClearedStatus::Cleared));
+}
+
+#[test]
+fn updates_todays_transaction_with_the_change_since_previous_days() {
+    let mock = MockYnab::start();
+    let account = mock.add_account("Crypto");
+    let yesterday = (Utc::now() - Duration::days(1))
+        .date_naive()
+        .format("%Y-%m-%d")
+        .to_string();
+    mock.add_transaction(account.id, &yesterday, 1_000_000, "Savings");
+    let dir = tempfile::tempdir().unwrap();
+
+    let first = run_sync(
+        &mock,
+        dir.path(),
+        &[(
+            "MANUAL_ASSETS",
+            r#"[{"symbol": "USDC", "quantity": 1234.5, "label": "Savings"}]"#,
+        )],
+    );
+    assert_success(&first);
+
+    let todays = mock
+        .transactions()
+        .into_iter()
+        .find(|t| t.date == today())
+        .expect("a transaction for today");
+    assert_eq!(todays.amount, 234_500);
+
+    let second = run_sync(
+        &mock,
+        dir.path(),
+        &[(
+            "MANUAL_ASSETS",
+            r#"[{"symbol": "USDC", "quantity": 1300, "label": "Savings"}]"#,
+        )],
+    );
+    assert_success(&second);
+
+    let transactions = mock.transactions();
+    assert_eq!(transactions.len(), 2);
+    assert_eq!(mock.accounts().len(), 1);
+
+    let updated = transactions.iter().find(|t| t.id == todays.id).unwrap();
+    assert_eq!(updated.amount, 300_000);
+
+    // the second run only asks for what changed since the first
+    let budget = format!("GET /v1/budgets/{}", mock.budget_id());
+    let refreshes = mock
+        .requests()
+        .into_iter()
+        .filter(|r| r.starts_with(&budget) && !r[budget.len()..].starts_with('/'))
+        .collect::<Vec<_>>();
+    assert_eq!(refreshes.len(), 2);
+    assert!(refreshes[1].contains("last_knowledge_of_server="));
+}
+
+#[test]
+fn fails_without_changes_when_the_token_is_rejected() {
+    let mock = MockYnab::start();
+    let dir = tempfile::tempdir().unwrap();
+
+    let output = run_sync(
+        &mock,
+        dir.path(),
+        &[
+            ("YNAB_ACCESS_TOKEN", "not-the-token"),
+            (
+                "MANUAL_ASSETS",
+                r#"[{"symbol": "USDC", "quantity": 1, "label": "Savings"}]"#,
+            ),
+        ],
+    );
+
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("YnabAuth"));
+    assert!(mock.accounts().is_empty());
+    assert!(mock.transactions().is_empty());
+}
+
+#[test]
+fn exits_before_syncing_when_the_http_settings_are_invalid() {
+    let mock = MockYnab::start();
+    let dir = tempfile::tempdir().unwrap();
+
+    let output = run_sync(
+        &mock,
+        dir.path(),
+        &[
+            ("HTTP_CA_CERTS", "missing.pem"),
+            (
+                "MANUAL_ASSETS",
+                r#"[{"symbol": "USDC", "quantity": 1, "label": "Savings"}]"#,
+            ),
+        ],
+    );
+
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("Invalid HTTP settings"));
+    assert!(mock.requests().is_empty());
+}
+
+#[test]
+fn syncs_only_their_own_wallets_into_a_users_budget() {
+    let mock = MockYnab::start();
+    let dir = tempfile::tempdir().unwrap();
+    let credentials = dir.path().join("ynab-credentials.json");
+    std::fs::write(
+        &credentials,
+        serde_json::json!({
+            "alice": {
+                "access_token": mock.token(),
+                "refresh_token": "unused",
+                "expires_at": Utc::now() + Duration::days(1),
+            }
+        })
+        .to_string(),
+    )
+    .unwrap();
+
+    let output = run_sync(
+        &mock,
+        dir.path(),
+        &[
+            ("YNAB_ACCESS_TOKEN", ""),
+            ("YNAB_CREDENTIALS", credentials.to_str().unwrap()),
+            (
+                "MANUAL_ASSETS",
+                r#"[{"symbol": "USDC", "quantity": 100, "label": "Family savings"}]"#,
+            ),
+            (
+                "USER_ALICE_MANUAL_ASSETS",
+                r#"[{"symbol": "DAI", "quantity": 50, "label": "Alice's savings"}]"#,
+            ),
+        ],
+    );
+    assert_success(&output);
+
+    let summary = mock
+        .transactions()
+        .into_iter()
+        .map(|t| (t.payee_name.flatten(), t.amount))
+        .collect::<Vec<_>>();
+    assert_eq!(summary, vec![(Some("Alice's savings".to_string()), 50_000)]);
+    assert!(dir.path().join("history-alice.sqlite").exists());
+}
@@ -0,0 +1,71 @@
+/*
+ * YNAB API Endpoints
+ *
+ * Our API uses a REST based design, leverages the JSON data format, and relies upon HTTPS for transport. We respond with meaningful HTTP response codes and if an error occurs, we include error details in the response body.  API Documentation is at https://api.ynab.com
+ *
+ * The version of the OpenAPI document: 1.72.1
+ *
+ * Generated by: https://openapi-generator.tech
+ */
+use std::sync::Arc;
+
+use crate::apis::rate_limit::RateLimit;
+use crate::apis::retry::RetryPolicy;
+
+pub use crate::apis::configuration::{ApiKey, BasicAuth, RequestHook};
+
+/// The configuration of the blocking API, the same as the async one but with
+/// a blocking client.
+#[derive(Debug, Clone)]
+pub struct Configuration {
+    pub base_path: String,
+    pub user_agent: Option<String>,
+    pub client: reqwest::blocking::Client,
+    pub basic_auth: Option<BasicAuth>,
+    pub oauth_access_token: Option<String>,
+    pub bearer_access_token: Option<String>,
+    pub api_key: Option<ApiKey>,
+    /// Rate limit budgets by token, shared with the async API by default.
+    pub rate_limit: Arc<RateLimit>,
+    pub retry: RetryPolicy,
+    /// Called before every request is sent, retries included.
+    pub on_request: Option<RequestHook>,
+}
+
+impl Configuration {
+    pub fn new() -> Configuration {
+        Configuration::default()
+    }
+
+    /// The token requests are authorized with.
+    pub fn token(&self) -> Option<&str> {
+        self.bearer_access_token
+            .as_deref()
+            .or(self.oauth_access_token.as_deref())
+    }
+
+    /// Requests left in the current rate limit window for this configuration's
+    /// token, to check before starting a batch.
+    pub fn remaining_requests(&self) -> u32 {
+        self.rate_limit.remaining(self.token().unwrap_or_default())
+    }
+}
+
+impl Default for Configuration {
+    fn default() -> Self {
+        Configuration {
+            base_path: "https://api.ynab.com/v1".to_owned(),
+            user_agent: Some(
+                concat!(env!("CARGO_PKG_NAME"), "/", env!("CARGO_PKG_VERSION")).to_owned(),
+            ),
+            client: reqwest::blocking::Client::new(),
+            basic_auth: None,
+            oauth_access_token: None,
+            bearer_access_token: None,
+            api_key: None,
+            rate_limit: RateLimit::shared(),
+            retry: RetryPolicy::default(),
+            on_request: None,
+        }
+    }
+}
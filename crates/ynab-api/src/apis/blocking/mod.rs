@@ -0,0 +1,63 @@
+//! Synchronous versions of the endpoints, for callers without an async
+//! runtime. They take a blocking [`configuration::Configuration`] and return
+//! the same models and errors as the async API.
+//!
+//! Must not be called from within an async runtime, which the blocking
+//! client panics on.
+
+pub use super::{Error, ResponseContent};
+use super::retry;
+
+/// Executes a request with the configured client, keeping track of the rate
+/// limit reported in the response. Requests rejected with `429 Too Many
+/// Requests`, or idempotent ones failing with a transient server error, a
+/// connection error or a timeout, are retried as set by the configuration's
+/// retry policy.
+pub(crate) fn execute(configuration: &configuration::Configuration, request: reqwest::blocking::Request) -> Result<reqwest::blocking::Response, reqwest::Error> {
+    let token = configuration.token().unwrap_or_default();
+    let method = request.method().clone();
+    let mut attempt = 0;
+
+    loop {
+        let next = if attempt < configuration.retry.max_retries { request.try_clone() } else { None };
+
+        // the last attempt, or a request with a streamed body that cannot be retried
+        let Some(next) = next else {
+            return send(configuration, token, request);
+        };
+
+        let retry_after = match send(configuration, token, next) {
+            Ok(response) if !retry::RetryPolicy::is_retryable(&method, response.status()) => return Ok(response),
+            Ok(response) => retry::retry_after(response.headers()),
+            Err(e) if retry::RetryPolicy::is_retryable_error(&method, &e) => None,
+            Err(e) => return Err(e),
+        };
+
+        let delay = configuration.retry.delay(attempt, retry_after);
+        std::thread::sleep(delay);
+        attempt += 1;
+    }
+}
+
+fn send(configuration: &configuration::Configuration, token: &str, request: reqwest::blocking::Request) -> Result<reqwest::blocking::Response, reqwest::Error> {
+    configuration.rate_limit.record(token);
+    if let Some(hook) = &configuration.on_request {
+        hook.call(request.method(), request.url());
+    }
+    let response = configuration.client.execute(request)?;
+    configuration.rate_limit.update(token, response.headers());
+    Ok(response)
+}
+
+pub mod accounts_api;
+pub mod budgets_api;
+pub mod categories_api;
+pub mod money_movements_api;
+pub mod months_api;
+pub mod payee_locations_api;
+pub mod payees_api;
+pub mod scheduled_transactions_api;
+pub mod transactions_api;
+pub mod user_api;
+
+pub mod configuration;
@@ -7,6 +7,11 @@
  *
  * Generated by: https://openapi-generator.tech
  */
+use std::fmt;
+use std::sync::Arc;
+
+use super::rate_limit::RateLimit;
+use super::retry::RetryPolicy;
 
 #[derive(Debug, Clone)]
 pub struct Configuration {
@@ -17,6 +22,34 @@ pub struct Configuration {
     pub oauth_access_token: Option<String>,
     pub bearer_access_token: Option<String>,
     pub api_key: Option<ApiKey>,
+    /// Rate limit budgets by token, shared by all configurations by default.
+    pub rate_limit: Arc<RateLimit>,
+    pub retry: RetryPolicy,
+    /// Called before every request is sent, retries included.
+    pub on_request: Option<RequestHook>,
+}
+
+/// A callback given the method and URL of each request sent, e.g. to count
+/// requests in metrics.
+#[derive(Clone)]
+pub struct RequestHook(Arc<HookFn>);
+
+type HookFn = dyn Fn(&reqwest::Method, &reqwest::Url) + Send + Sync;
+
+impl RequestHook {
+    pub fn new(hook: impl Fn(&reqwest::Method, &reqwest::Url) + Send + Sync + 'static) -> Self {
+        RequestHook(Arc::new(hook))
+    }
+
+    pub fn call(&self, method: &reqwest::Method, url: &reqwest::Url) {
+        (self.0)(method, url)
+    }
+}
+
+impl fmt::Debug for RequestHook {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("RequestHook")
+    }
 }
 
 pub type BasicAuth = (String, Option<String>);
@@ -31,18 +64,36 @@ impl Configuration {
     pub fn new() -> Configuration {
         Configuration::default()
     }
+
+    /// The token requests are authorized with.
+    pub fn token(&self) -> Option<&str> {
+        self.bearer_access_token
+            .as_deref()
+            .or(self.oauth_access_token.as_deref())
+    }
+
+    /// Requests left in the current rate limit window for this configuration's
+    /// token, to check before starting a batch.
+    pub fn remaining_requests(&self) -> u32 {
+        self.rate_limit.remaining(self.token().unwrap_or_default())
+    }
 }
 
 impl Default for Configuration {
     fn default() -> Self {
         Configuration {
             base_path: "https://api.ynab.com/v1".to_owned(),
-            user_agent: Some("OpenAPI-Generator/1.72.1/rust".to_owned()),
+            user_agent: Some(
+                concat!(env!("CARGO_PKG_NAME"), "/", env!("CARGO_PKG_VERSION")).to_owned(),
+            ),
             client: reqwest::Client::new(),
             basic_auth: None,
             oauth_access_token: None,
             bearer_access_token: None,
             api_key: None,
+            rate_limit: RateLimit::shared(),
+            retry: RetryPolicy::default(),
+            on_request: None,
         }
     }
 }
@@ -1,6 +1,8 @@
 use std::error;
 use std::fmt;
 
+use crate::models;
+
 #[derive(Debug, Clone)]
 pub struct ResponseContent<T> {
     pub status: reqwest::StatusCode,
@@ -16,13 +18,70 @@ pub enum Error<T> {
     ResponseError(ResponseContent<T>),
 }
 
+/// Longest response body quoted in an error message when YNAB did not send
+/// an error detail.
+const MAX_QUOTED_CONTENT: usize = 200;
+
+impl <T> ResponseContent<T> {
+    /// The error YNAB described in the response body, whichever typed entity
+    /// it was parsed into.
+    pub fn error_detail(&self) -> Option<models::ErrorDetail> {
+        serde_json::from_str::<models::ErrorResponse>(&self.content).ok().map(|r| *r.error)
+    }
+}
+
+impl <T> fmt::Display for ResponseContent<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "status code {}", self.status)?;
+
+        match self.error_detail() {
+            Some(detail) => write!(f, ": {} ({}): {}", detail.name, detail.id, detail.detail),
+            None if self.content.trim().is_empty() => Ok(()),
+            None => {
+                let content = self.content.trim();
+                match content.char_indices().nth(MAX_QUOTED_CONTENT) {
+                    Some((end, _)) => write!(f, ": {}...", &content[..end]),
+                    None => write!(f, ": {}", content),
+                }
+            }
+        }
+    }
+}
+
+impl <T> Error<T> {
+    /// The HTTP status of a failed response.
+    pub fn status(&self) -> Option<reqwest::StatusCode> {
+        match self {
+            Error::Reqwest(e) => e.status(),
+            Error::ResponseError(e) => Some(e.status),
+            _ => None,
+        }
+    }
+
+    /// The error YNAB described in a failed response.
+    pub fn error_detail(&self) -> Option<models::ErrorDetail> {
+        match self {
+            Error::ResponseError(e) => e.error_detail(),
+            _ => None,
+        }
+    }
+
+    /// The typed error entity of a failed response, when its body matched one.
+    pub fn entity(&self) -> Option<&T> {
+        match self {
+            Error::ResponseError(e) => e.entity.as_ref(),
+            _ => None,
+        }
+    }
+}
+
 impl <T> fmt::Display for Error<T> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let (module, e) = match self {
             Error::Reqwest(e) => ("reqwest", e.to_string()),
             Error::Serde(e) => ("serde", e.to_string()),
             Error::Io(e) => ("IO", e.to_string()),
-            Error::ResponseError(e) => ("response", format!("status code {}", e.status)),
+            Error::ResponseError(e) => ("response", e.to_string()),
         };
         write!(f, "error in {}: {}", module, e)
     }
@@ -57,6 +116,47 @@ impl <T> From<std::io::Error> for Error<T> {
     }
 }
 
+/// Executes a request with the configured client, keeping track of the rate
+/// limit reported in the response. Requests rejected with `429 Too Many
+/// Requests`, or idempotent ones failing with a transient server error, a
+/// connection error or a timeout, are retried as set by the configuration's
+/// retry policy.
+pub(crate) async fn execute(configuration: &configuration::Configuration, request: reqwest::Request) -> Result<reqwest::Response, reqwest::Error> {
+    let token = configuration.token().unwrap_or_default();
+    let method = request.method().clone();
+    let mut attempt = 0;
+
+    loop {
+        let next = if attempt < configuration.retry.max_retries { request.try_clone() } else { None };
+
+        // the last attempt, or a request with a streamed body that cannot be retried
+        let Some(next) = next else {
+            return send(configuration, token, request).await;
+        };
+
+        let retry_after = match send(configuration, token, next).await {
+            Ok(response) if !retry::RetryPolicy::is_retryable(&method, response.status()) => return Ok(response),
+            Ok(response) => retry::retry_after(response.headers()),
+            Err(e) if retry::RetryPolicy::is_retryable_error(&method, &e) => None,
+            Err(e) => return Err(e),
+        };
+
+        let delay = configuration.retry.delay(attempt, retry_after);
+        tokio::time::sleep(delay).await;
+        attempt += 1;
+    }
+}
+
+async fn send(configuration: &configuration::Configuration, token: &str, request: reqwest::Request) -> Result<reqwest::Response, reqwest::Error> {
+    configuration.rate_limit.record(token);
+    if let Some(hook) = &configuration.on_request {
+        hook.call(request.method(), request.url());
+    }
+    let response = configuration.client.execute(request).await?;
+    configuration.rate_limit.update(token, response.headers());
+    Ok(response)
+}
+
 pub fn urlencode<T: AsRef<str>>(s: T) -> String {
     ::url::form_urlencoded::byte_serialize(s.as_ref().as_bytes()).collect()
 }
@@ -93,6 +193,7 @@ pub fn parse_deep_object(prefix: &str, value: &serde_json::Value) -> Vec<(String
 pub mod accounts_api;
 pub mod budgets_api;
 pub mod categories_api;
+pub mod money_movements_api;
 pub mod months_api;
 pub mod payee_locations_api;
 pub mod payees_api;
@@ -100,4 +201,125 @@ pub mod scheduled_transactions_api;
 pub mod transactions_api;
 pub mod user_api;
 
+#[cfg(feature = "blocking")]
+pub mod blocking;
 pub mod configuration;
+pub mod rate_limit;
+pub mod retry;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::{Read, Write};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    fn response(status: u16, content: &str) -> ResponseContent<()> {
+        ResponseContent {
+            status: reqwest::StatusCode::from_u16(status).unwrap(),
+            content: content.to_string(),
+            entity: None,
+        }
+    }
+
+    #[test]
+    fn shows_the_error_detail_ynab_sent() {
+        let content = r#"{"error":{"id":"404.2","name":"resource_not_found","detail":"Resource not found"}}"#;
+
+        assert_eq!(
+            response(404, content).to_string(),
+            "status code 404 Not Found: resource_not_found (404.2): Resource not found"
+        );
+    }
+
+    #[test]
+    fn shows_only_the_status_of_an_empty_body() {
+        assert_eq!(response(502, " \n").to_string(), "status code 502 Bad Gateway");
+    }
+
+    #[test]
+    fn quotes_a_short_body_whole() {
+        let content = "é".repeat(MAX_QUOTED_CONTENT);
+
+        assert_eq!(
+            response(500, &content).to_string(),
+            format!("status code 500 Internal Server Error: {content}")
+        );
+    }
+
+    #[test]
+    fn truncates_a_long_body_on_a_character_boundary() {
+        let content = "é".repeat(MAX_QUOTED_CONTENT + 1);
+
+        assert_eq!(
+            response(500, &content).to_string(),
+            format!("status code 500 Internal Server Error: {}...", "é".repeat(MAX_QUOTED_CONTENT))
+        );
+    }
+
+    /// Answers one request per connection with each of the statuses in turn,
+    /// and returns the server's URL.
+    fn serve(statuses: &'static [u16]) -> String {
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+
+        std::thread::spawn(move || {
+            for status in statuses {
+                let (mut stream, _) = listener.accept().unwrap();
+                let _ = stream.read(&mut [0; 4096]);
+                write!(stream, "HTTP/1.1 {status} Status\r\nContent-Length: 0\r\nConnection: close\r\n\r\n").unwrap();
+            }
+        });
+
+        url
+    }
+
+    #[tokio::test]
+    async fn calls_the_request_hook_for_every_attempt() {
+        let requests = Arc::new(AtomicUsize::new(0));
+        let counter = requests.clone();
+
+        let mut configuration = configuration::Configuration::new();
+        configuration.retry.base_delay = Duration::from_millis(1);
+        configuration.on_request = Some(configuration::RequestHook::new(move |method, url| {
+            assert_eq!(method, reqwest::Method::GET);
+            assert_eq!(url.path(), "/user");
+            counter.fetch_add(1, Ordering::SeqCst);
+        }));
+
+        let url = serve(&[503, 200]);
+        let request = configuration.client.get(format!("{url}/user")).build().unwrap();
+        let response = execute(&configuration, request).await.unwrap();
+
+        assert_eq!(response.status(), 200);
+        assert_eq!(requests.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn retries_idempotent_requests_that_cannot_connect() {
+        // a port nothing listens on once the listener is dropped
+        let url = {
+            let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+            format!("http://{}/user", listener.local_addr().unwrap())
+        };
+
+        for (method, attempts) in [(reqwest::Method::GET, 3), (reqwest::Method::POST, 1)] {
+            let requests = Arc::new(AtomicUsize::new(0));
+            let counter = requests.clone();
+
+            let mut configuration = configuration::Configuration::new();
+            configuration.retry.max_retries = 2;
+            configuration.retry.base_delay = Duration::from_millis(1);
+            configuration.on_request = Some(configuration::RequestHook::new(move |_, _| {
+                counter.fetch_add(1, Ordering::SeqCst);
+            }));
+
+            let request = configuration.client.request(method.clone(), &url).build().unwrap();
+            let error = execute(&configuration, request).await.unwrap_err();
+
+            assert!(error.is_connect());
+            assert_eq!(requests.load(Ordering::SeqCst), attempts, "{method} attempts");
+        }
+    }
+}
@@ -0,0 +1,130 @@
+use std::collections::{HashMap, VecDeque};
+use std::sync::{Arc, LazyLock, Mutex};
+use std::time::{Duration, Instant};
+
+/// Header YNAB uses to report the requests used and allowed in the current
+/// rolling window, e.g. `36/200`.
+pub const RATE_LIMIT_HEADER: &str = "X-Rate-Limit";
+
+/// Requests YNAB allows per token and window, until it reports otherwise.
+pub const DEFAULT_LIMIT: u32 = 200;
+
+/// Length of YNAB's rolling rate limit window.
+pub const WINDOW: Duration = Duration::from_secs(3600);
+
+static SHARED: LazyLock<Arc<RateLimit>> = LazyLock::new(|| Arc::new(RateLimit::default()));
+
+/// Requests made with a token in the current window and the latest usage YNAB
+/// reported for it.
+#[derive(Debug, Default)]
+struct TokenBudget {
+    requests: VecDeque<Instant>,
+    /// Requests used and allowed, and when YNAB reported them.
+    reported: Option<(u32, u32, Instant)>,
+}
+
+impl TokenBudget {
+    fn expire(&mut self, now: Instant) {
+        while self
+            .requests
+            .front()
+            .is_some_and(|at| now.duration_since(*at) >= WINDOW)
+        {
+            self.requests.pop_front();
+        }
+
+        if self
+            .reported
+            .is_some_and(|(_, _, at)| now.duration_since(at) >= WINDOW)
+        {
+            self.reported = None;
+        }
+    }
+
+    /// Estimates the requests used in the window: the latest count reported by
+    /// YNAB, which includes other clients of the token, plus the requests made
+    /// since.
+    fn used(&self) -> u32 {
+        let local = self.requests.len() as u32;
+
+        match self.reported {
+            Some((used, _, at)) => {
+                let since = self.requests.iter().filter(|r| **r > at).count() as u32;
+                local.max(used + since)
+            }
+            None => local,
+        }
+    }
+
+    fn limit(&self) -> u32 {
+        self.reported.map_or(DEFAULT_LIMIT, |(_, limit, _)| limit)
+    }
+}
+
+/// Tracks the rate limit budget of every token used with it. Configurations
+/// share one tracker by default, so a token's budget is counted across all of
+/// them.
+#[derive(Debug, Default)]
+pub struct RateLimit {
+    tokens: Mutex<HashMap<String, TokenBudget>>,
+}
+
+impl RateLimit {
+    /// The tracker shared by all configurations by default.
+    pub fn shared() -> Arc<RateLimit> {
+        SHARED.clone()
+    }
+
+    /// Counts a request about to be sent with a token.
+    pub fn record(&self, token: &str) {
+        let now = Instant::now();
+        let mut tokens = self.tokens.lock().unwrap();
+        let budget = tokens.entry(token.to_owned()).or_default();
+
+        budget.expire(now);
+        budget.requests.push_back(now);
+    }
+
+    /// Updates a token's budget from the headers of a response, if present.
+    pub fn update(&self, token: &str, headers: &reqwest::header::HeaderMap) {
+        let parsed = headers
+            .get(RATE_LIMIT_HEADER)
+            .and_then(|v| v.to_str().ok())
+            .and_then(|v| v.split_once('/'))
+            .and_then(|(used, limit)| {
+                Some((used.trim().parse().ok()?, limit.trim().parse().ok()?))
+            });
+
+        if let Some((used, limit)) = parsed {
+            let mut tokens = self.tokens.lock().unwrap();
+            let budget = tokens.entry(token.to_owned()).or_default();
+
+            budget.reported = Some((used, limit, Instant::now()));
+        }
+    }
+
+    fn with_budget<T>(&self, token: &str, f: impl FnOnce(&TokenBudget) -> T) -> Option<T> {
+        let mut tokens = self.tokens.lock().unwrap();
+        let budget = tokens.get_mut(token)?;
+
+        budget.expire(Instant::now());
+        Some(f(budget))
+    }
+
+    /// Requests used with a token in the current window, if it has been used.
+    pub fn used(&self, token: &str) -> Option<u32> {
+        self.with_budget(token, TokenBudget::used)
+    }
+
+    /// Requests allowed per window for a token, if it has been used.
+    pub fn limit(&self, token: &str) -> Option<u32> {
+        self.with_budget(token, TokenBudget::limit)
+    }
+
+    /// Requests left for a token in the current window. Tokens that have not
+    /// been used yet have their whole budget left.
+    pub fn remaining(&self, token: &str) -> u32 {
+        self.with_budget(token, |b| b.limit().saturating_sub(b.used()))
+            .unwrap_or(DEFAULT_LIMIT)
+    }
+}
@@ -0,0 +1,160 @@
+use std::time::Duration;
+
+/// How requests failing with `429 Too Many Requests`, a transient server
+/// error, or a connection error or timeout are retried. Only idempotent
+/// methods are retried after the latter two, since YNAB may have applied a
+/// `POST` before failing, e.g. created a transaction.
+#[derive(Debug, Clone)]
+pub struct RetryPolicy {
+    /// Retries after the first attempt; zero disables retrying.
+    pub max_retries: u32,
+    /// Delay before the first retry, doubled for each following one.
+    pub base_delay: Duration,
+    /// Longest delay between two attempts, including a `Retry-After` asked for
+    /// by the server.
+    pub max_delay: Duration,
+}
+
+impl RetryPolicy {
+    /// A policy that never retries.
+    pub fn none() -> Self {
+        RetryPolicy {
+            max_retries: 0,
+            ..RetryPolicy::default()
+        }
+    }
+
+    /// Whether a request with this method that got a response with this
+    /// status is worth retrying.
+    pub fn is_retryable(method: &reqwest::Method, status: reqwest::StatusCode) -> bool {
+        match status.as_u16() {
+            429 => true,
+            500 | 502 | 503 | 504 => method.is_idempotent(),
+            _ => false,
+        }
+    }
+
+    /// Whether a request with this method that failed without a response is
+    /// worth retrying.
+    pub fn is_retryable_error(method: &reqwest::Method, error: &reqwest::Error) -> bool {
+        method.is_idempotent() && (error.is_connect() || error.is_timeout())
+    }
+
+    /// Delay before retry number `attempt`, counted from zero: exponential
+    /// backoff with jitter, or the `Retry-After` delay when the server sent
+    /// one.
+    pub fn delay(&self, attempt: u32, retry_after: Option<Duration>) -> Duration {
+        if let Some(retry_after) = retry_after {
+            return retry_after.min(self.max_delay);
+        }
+
+        let backoff = self
+            .base_delay
+            .saturating_mul(2u32.saturating_pow(attempt))
+            .min(self.max_delay);
+
+        // equal jitter: half the backoff plus a random share of the other half,
+        // so clients rate limited together do not retry in lockstep
+        backoff / 2 + backoff / 2 * jitter_permille() / 1000
+    }
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        RetryPolicy {
+            max_retries: 3,
+            base_delay: Duration::from_secs(1),
+            max_delay: Duration::from_secs(60),
+        }
+    }
+}
+
+/// A random number in `0..1000`, or the middle of the range when the system
+/// has no randomness to offer.
+fn jitter_permille() -> u32 {
+    let mut bytes = [0; 4];
+
+    match getrandom::getrandom(&mut bytes) {
+        Ok(()) => u32::from_le_bytes(bytes) % 1000,
+        Err(_) => 500,
+    }
+}
+
+/// Reads a `Retry-After` header given in seconds.
+pub(crate) fn retry_after(headers: &reqwest::header::HeaderMap) -> Option<Duration> {
+    headers
+        .get(reqwest::header::RETRY_AFTER)
+        .and_then(|v| v.to_str().ok())
+        .and_then(|v| v.trim().parse().ok())
+        .map(Duration::from_secs)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use reqwest::{Method, StatusCode};
+
+    #[test]
+    fn retries_rate_limited_requests_of_any_method() {
+        for method in [
+            Method::GET,
+            Method::POST,
+            Method::PUT,
+            Method::PATCH,
+            Method::DELETE,
+        ] {
+            assert!(RetryPolicy::is_retryable(
+                &method,
+                StatusCode::TOO_MANY_REQUESTS
+            ));
+        }
+    }
+
+    #[test]
+    fn retries_server_errors_of_idempotent_requests_only() {
+        for status in [500, 502, 503, 504].map(|s| StatusCode::from_u16(s).unwrap()) {
+            assert!(RetryPolicy::is_retryable(&Method::GET, status));
+            assert!(RetryPolicy::is_retryable(&Method::PUT, status));
+            assert!(RetryPolicy::is_retryable(&Method::DELETE, status));
+            assert!(!RetryPolicy::is_retryable(&Method::POST, status));
+            assert!(!RetryPolicy::is_retryable(&Method::PATCH, status));
+        }
+    }
+
+    #[test]
+    fn does_not_retry_other_statuses() {
+        for status in [200, 400, 401, 404, 409, 501] {
+            let status = StatusCode::from_u16(status).unwrap();
+            assert!(!RetryPolicy::is_retryable(&Method::GET, status));
+        }
+    }
+
+    #[test]
+    fn jitters_the_backoff_within_its_upper_half() {
+        let policy = RetryPolicy::default();
+
+        for attempt in 0..4 {
+            let backoff = policy.base_delay * 2u32.pow(attempt);
+            let delays = (0..20)
+                .map(|_| policy.delay(attempt, None))
+                .collect::<Vec<_>>();
+
+            assert!(delays.iter().all(|d| *d >= backoff / 2 && *d <= backoff));
+            assert!(delays.iter().any(|d| *d != delays[0]));
+        }
+    }
+
+    #[test]
+    fn waits_as_long_as_the_server_asks_up_to_the_max_delay() {
+        let policy = RetryPolicy::default();
+
+        assert_eq!(
+            policy.delay(0, Some(Duration::from_secs(5))),
+            Duration::from_secs(5)
+        );
+        assert_eq!(
+            policy.delay(0, Some(Duration::from_secs(600))),
+            policy.max_delay
+        );
+    }
+}
@@ -23,6 +23,14 @@ pub enum CreateScheduledTransactionError {
     UnknownValue(serde_json::Value),
 }
 
+/// struct for typed errors of method [`delete_scheduled_transaction`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum DeleteScheduledTransactionError {
+    Status404(models::ErrorResponse),
+    UnknownValue(serde_json::Value),
+}
+
 /// struct for typed errors of method [`get_scheduled_transaction_by_id`]
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(untagged)]
@@ -41,6 +49,15 @@ pub enum GetScheduledTransactionsError {
     UnknownValue(serde_json::Value),
 }
 
+/// struct for typed errors of method [`update_scheduled_transaction`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum UpdateScheduledTransactionError {
+    Status400(models::ErrorResponse),
+    Status404(models::ErrorResponse),
+    UnknownValue(serde_json::Value),
+}
+
 
 /// Creates a single scheduled transaction (a transaction with a future date).
 pub async fn create_scheduled_transaction(configuration: &configuration::Configuration, budget_id: &str, data: models::PostScheduledTransactionWrapper) -> Result<models::ScheduledTransactionResponse, Error<CreateScheduledTransactionError>> {
@@ -60,7 +77,7 @@ pub async fn create_scheduled_transaction(configuration: &configuration::Configu
     local_var_req_builder = local_var_req_builder.json(&data);
 
     let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
+    let local_var_resp = crate::apis::execute(local_var_configuration, local_var_req).await?;
 
     let local_var_status = local_var_resp.status();
     let local_var_content = local_var_resp.text().await?;
@@ -74,6 +91,37 @@ pub async fn create_scheduled_transaction(configuration: &configuration::Configu
     }
 }
 
+/// Deletes a scheduled transaction
+pub async fn delete_scheduled_transaction(configuration: &configuration::Configuration, budget_id: &str, scheduled_transaction_id: &str) -> Result<models::ScheduledTransactionResponse, Error<DeleteScheduledTransactionError>> {
+    let local_var_configuration = configuration;
+
+    let local_var_client = &local_var_configuration.client;
+
+    let local_var_uri_str = format!("{}/budgets/{budget_id}/scheduled_transactions/{scheduled_transaction_id}", local_var_configuration.base_path, budget_id=crate::apis::urlencode(budget_id), scheduled_transaction_id=crate::apis::urlencode(scheduled_transaction_id));
+    let mut local_var_req_builder = local_var_client.request(reqwest::Method::DELETE, local_var_uri_str.as_str());
+
+    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
+        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
+    }
+    if let Some(ref local_var_token) = local_var_configuration.bearer_access_token {
+        local_var_req_builder = local_var_req_builder.bearer_auth(local_var_token.to_owned());
+    };
+
+    let local_var_req = local_var_req_builder.build()?;
+    let local_var_resp = crate::apis::execute(local_var_configuration, local_var_req).await?;
+
+    let local_var_status = local_var_resp.status();
+    let local_var_content = local_var_resp.text().await?;
+
+    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
+        serde_json::from_str(&local_var_content).map_err(Error::from)
+    } else {
+        let local_var_entity: Option<DeleteScheduledTransactionError> = serde_json::from_str(&local_var_content).ok();
+        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
+        Err(Error::ResponseError(local_var_error))
+    }
+}
+
 /// Returns a single scheduled transaction
 pub async fn get_scheduled_transaction_by_id(configuration: &configuration::Configuration, budget_id: &str, scheduled_transaction_id: &str) -> Result<models::ScheduledTransactionResponse, Error<GetScheduledTransactionByIdError>> {
     let local_var_configuration = configuration;
@@ -91,7 +139,7 @@ pub async fn get_scheduled_transaction_by_id(configuration: &configuration::Conf
     };
 
     let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
+    let local_var_resp = crate::apis::execute(local_var_configuration, local_var_req).await?;
 
     let local_var_status = local_var_resp.status();
     let local_var_content = local_var_resp.text().await?;
@@ -125,7 +173,7 @@ pub async fn get_scheduled_transactions(configuration: &configuration::Configura
     };
 
     let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
+    let local_var_resp = crate::apis::execute(local_var_configuration, local_var_req).await?;
 
     let local_var_status = local_var_resp.status();
     let local_var_content = local_var_resp.text().await?;
@@ -139,3 +187,35 @@ pub async fn get_scheduled_transactions(configuration: &configuration::Configura
     }
 }
 
+/// Updates a single scheduled transaction
+pub async fn update_scheduled_transaction(configuration: &configuration::Configuration, budget_id: &str, scheduled_transaction_id: &str, data: models::PutScheduledTransactionWrapper) -> Result<models::ScheduledTransactionResponse, Error<UpdateScheduledTransactionError>> {
+    let local_var_configuration = configuration;
+
+    let local_var_client = &local_var_configuration.client;
+
+    let local_var_uri_str = format!("{}/budgets/{budget_id}/scheduled_transactions/{scheduled_transaction_id}", local_var_configuration.base_path, budget_id=crate::apis::urlencode(budget_id), scheduled_transaction_id=crate::apis::urlencode(scheduled_transaction_id));
+    let mut local_var_req_builder = local_var_client.request(reqwest::Method::PUT, local_var_uri_str.as_str());
+
+    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
+        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
+    }
+    if let Some(ref local_var_token) = local_var_configuration.bearer_access_token {
+        local_var_req_builder = local_var_req_builder.bearer_auth(local_var_token.to_owned());
+    };
+    local_var_req_builder = local_var_req_builder.json(&data);
+
+    let local_var_req = local_var_req_builder.build()?;
+    let local_var_resp = crate::apis::execute(local_var_configuration, local_var_req).await?;
+
+    let local_var_status = local_var_resp.status();
+    let local_var_content = local_var_resp.text().await?;
+
+    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
+        serde_json::from_str(&local_var_content).map_err(Error::from)
+    } else {
+        let local_var_entity: Option<UpdateScheduledTransactionError> = serde_json::from_str(&local_var_content).ok();
+        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
+        Err(Error::ResponseError(local_var_error))
+    }
+}
+
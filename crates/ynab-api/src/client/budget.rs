@@ -0,0 +1,476 @@
+use super::{
+    AccountId, BudgetId, CategoryId, Money, PayeeId, ScheduledTransactionId, TransactionId,
+    TransactionParams, YnabClient,
+};
+use crate::apis::accounts_api::{self, CreateAccountError, GetAccountByIdError, GetAccountsError};
+use crate::apis::budgets_api::{self, GetBudgetSettingsByIdError};
+use crate::apis::categories_api::{
+    self, CreateCategoryError, GetCategoriesError, GetCategoryByIdError, UpdateCategoryError,
+};
+use crate::apis::money_movements_api::{
+    self, GetMoneyMovementGroupsByMonthError, GetMoneyMovementGroupsError,
+    GetMoneyMovementsByMonthError, GetMoneyMovementsError,
+};
+use crate::apis::months_api::{self, GetBudgetMonthError, GetBudgetMonthsError};
+use crate::apis::payees_api::{self, GetPayeeByIdError, GetPayeesError, UpdatePayeeError};
+use crate::apis::scheduled_transactions_api::{
+    self, CreateScheduledTransactionError, DeleteScheduledTransactionError,
+    GetScheduledTransactionByIdError, GetScheduledTransactionsError,
+    UpdateScheduledTransactionError,
+};
+use crate::apis::transactions_api::{
+    self, CreateTransactionError, DeleteTransactionError, GetTransactionByIdError,
+    GetTransactionsByAccountError, GetTransactionsError, UpdateTransactionError,
+};
+use crate::apis::Error;
+use crate::models::{
+    Account, AccountType, BudgetSettings, Category, CategoryGroupWithCategories, MoneyMovement,
+    MoneyMovementGroup, MonthDetail, MonthSummary, NewCategory, PatchCategoryWrapper,
+    PatchPayeeWrapper, Payee, PostAccountWrapper, PostCategoryWrapper,
+    PostScheduledTransactionWrapper, PostTransactionsWrapper, PutScheduledTransactionWrapper,
+    PutTransactionWrapper, SaveAccount, SaveCategory, SavePayee, SaveScheduledTransaction,
+    ScheduledTransactionDetail, TransactionDetail,
+};
+
+/// The endpoints of one budget.
+#[derive(Debug, Clone)]
+pub struct BudgetHandle<'a> {
+    client: &'a YnabClient,
+    id: BudgetId,
+}
+
+impl<'a> BudgetHandle<'a> {
+    pub(super) fn new(client: &'a YnabClient, id: BudgetId) -> Self {
+        BudgetHandle { client, id }
+    }
+
+    pub fn id(&self) -> &BudgetId {
+        &self.id
+    }
+
+    pub async fn settings(&self) -> Result<BudgetSettings, Error<GetBudgetSettingsByIdError>> {
+        let response =
+            budgets_api::get_budget_settings_by_id(self.client.configuration(), self.id.as_str())
+                .await?;
+        Ok(*response.data.settings)
+    }
+
+    pub fn accounts(&self) -> Accounts<'_> {
+        Accounts { budget: self }
+    }
+
+    pub fn transactions(&self) -> Transactions<'_> {
+        Transactions { budget: self }
+    }
+
+    pub fn scheduled_transactions(&self) -> ScheduledTransactions<'_> {
+        ScheduledTransactions { budget: self }
+    }
+
+    pub fn categories(&self) -> Categories<'_> {
+        Categories { budget: self }
+    }
+
+    pub fn payees(&self) -> Payees<'_> {
+        Payees { budget: self }
+    }
+
+    pub fn months(&self) -> Months<'_> {
+        Months { budget: self }
+    }
+
+    pub fn money_movements(&self) -> MoneyMovements<'_> {
+        MoneyMovements { budget: self }
+    }
+}
+
+macro_rules! endpoints {
+    ($name:ident) => {
+        #[derive(Debug, Clone, Copy)]
+        pub struct $name<'a> {
+            budget: &'a BudgetHandle<'a>,
+        }
+
+        impl $name<'_> {
+            fn configuration(&self) -> &crate::apis::configuration::Configuration {
+                self.budget.client.configuration()
+            }
+
+            fn budget_id(&self) -> &str {
+                self.budget.id.as_str()
+            }
+        }
+    };
+}
+
+endpoints!(Accounts);
+endpoints!(Transactions);
+endpoints!(ScheduledTransactions);
+endpoints!(Categories);
+endpoints!(Payees);
+endpoints!(Months);
+endpoints!(MoneyMovements);
+
+impl Accounts<'_> {
+    pub async fn list(&self) -> Result<Vec<Account>, Error<GetAccountsError>> {
+        let response =
+            accounts_api::get_accounts(self.configuration(), self.budget_id(), None).await?;
+        Ok(response.data.accounts)
+    }
+
+    pub async fn get(&self, id: AccountId) -> Result<Account, Error<GetAccountByIdError>> {
+        let response = accounts_api::get_account_by_id(
+            self.configuration(),
+            self.budget_id(),
+            &id.to_string(),
+        )
+        .await?;
+        Ok(*response.data.account)
+    }
+
+    pub async fn create(
+        &self,
+        name: &str,
+        r#type: AccountType,
+        balance: Money,
+    ) -> Result<Account, Error<CreateAccountError>> {
+        let response = accounts_api::create_account(
+            self.configuration(),
+            self.budget_id(),
+            PostAccountWrapper {
+                account: Box::new(SaveAccount {
+                    name: name.to_owned(),
+                    r#type,
+                    balance: balance.milliunits(),
+                }),
+            },
+        )
+        .await?;
+        Ok(*response.data.account)
+    }
+}
+
+impl Transactions<'_> {
+    /// Lists the budget's transactions, optionally only those on or after a
+    /// `YYYY-MM-DD` date.
+    pub async fn list(
+        &self,
+        since_date: Option<&str>,
+    ) -> Result<Vec<TransactionDetail>, Error<GetTransactionsError>> {
+        let response = transactions_api::get_transactions(
+            self.configuration(),
+            self.budget_id(),
+            since_date.map(str::to_owned),
+            None,
+            None,
+        )
+        .await?;
+        Ok(response.data.transactions)
+    }
+
+    /// Lists the transactions of an account, optionally only those on or
+    /// after a `YYYY-MM-DD` date.
+    pub async fn by_account(
+        &self,
+        account: AccountId,
+        since_date: Option<&str>,
+    ) -> Result<Vec<TransactionDetail>, Error<GetTransactionsByAccountError>> {
+        let response = transactions_api::get_transactions_by_account(
+            self.configuration(),
+            self.budget_id(),
+            &account.to_string(),
+            since_date.map(str::to_owned),
+            None,
+            None,
+        )
+        .await?;
+        Ok(response.data.transactions)
+    }
+
+    pub async fn get(
+        &self,
+        id: &TransactionId,
+    ) -> Result<TransactionDetail, Error<GetTransactionByIdError>> {
+        let response = transactions_api::get_transaction_by_id(
+            self.configuration(),
+            self.budget_id(),
+            id.as_str(),
+        )
+        .await?;
+        Ok(*response.data.transaction)
+    }
+
+    /// Creates a transaction. Returns `None` when it was not saved because its
+    /// import id is a duplicate.
+    pub async fn create(
+        &self,
+        transaction: TransactionParams,
+    ) -> Result<Option<TransactionDetail>, Error<CreateTransactionError>> {
+        let response = transactions_api::create_transaction(
+            self.configuration(),
+            self.budget_id(),
+            PostTransactionsWrapper {
+                transaction: Some(Box::new(transaction.into())),
+                ..Default::default()
+            },
+        )
+        .await?;
+        Ok(response.data.transaction.map(|t| *t))
+    }
+
+    pub async fn update(
+        &self,
+        id: &TransactionId,
+        transaction: TransactionParams,
+    ) -> Result<TransactionDetail, Error<UpdateTransactionError>> {
+        let response = transactions_api::update_transaction(
+            self.configuration(),
+            self.budget_id(),
+            id.as_str(),
+            PutTransactionWrapper {
+                transaction: Box::new(transaction.into()),
+            },
+        )
+        .await?;
+        Ok(*response.data.transaction)
+    }
+
+    pub async fn delete(
+        &self,
+        id: &TransactionId,
+    ) -> Result<TransactionDetail, Error<DeleteTransactionError>> {
+        let response = transactions_api::delete_transaction(
+            self.configuration(),
+            self.budget_id(),
+            id.as_str(),
+        )
+        .await?;
+        Ok(*response.data.transaction)
+    }
+
+    /// Deletes several transactions, one request each as YNAB has no bulk
+    /// delete. Stops at the first failure, leaving the rest in place.
+    pub async fn delete_many(
+        &self,
+        ids: &[TransactionId],
+    ) -> Result<Vec<TransactionDetail>, Error<DeleteTransactionError>> {
+        let mut deleted = Vec::with_capacity(ids.len());
+
+        for id in ids {
+            deleted.push(self.delete(id).await?);
+        }
+
+        Ok(deleted)
+    }
+}
+
+impl ScheduledTransactions<'_> {
+    pub async fn list(
+        &self,
+    ) -> Result<Vec<ScheduledTransactionDetail>, Error<GetScheduledTransactionsError>> {
+        let response = scheduled_transactions_api::get_scheduled_transactions(
+            self.configuration(),
+            self.budget_id(),
+            None,
+        )
+        .await?;
+        Ok(response.data.scheduled_transactions)
+    }
+
+    pub async fn get(
+        &self,
+        id: ScheduledTransactionId,
+    ) -> Result<ScheduledTransactionDetail, Error<GetScheduledTransactionByIdError>> {
+        let response = scheduled_transactions_api::get_scheduled_transaction_by_id(
+            self.configuration(),
+            self.budget_id(),
+            &id.to_string(),
+        )
+        .await?;
+        Ok(*response.data.scheduled_transaction)
+    }
+
+    pub async fn create(
+        &self,
+        scheduled_transaction: SaveScheduledTransaction,
+    ) -> Result<ScheduledTransactionDetail, Error<CreateScheduledTransactionError>> {
+        let response = scheduled_transactions_api::create_scheduled_transaction(
+            self.configuration(),
+            self.budget_id(),
+            PostScheduledTransactionWrapper::new(scheduled_transaction),
+        )
+        .await?;
+        Ok(*response.data.scheduled_transaction)
+    }
+
+    pub async fn update(
+        &self,
+        id: ScheduledTransactionId,
+        scheduled_transaction: SaveScheduledTransaction,
+    ) -> Result<ScheduledTransactionDetail, Error<UpdateScheduledTransactionError>> {
+        let response = scheduled_transactions_api::update_scheduled_transaction(
+            self.configuration(),
+            self.budget_id(),
+            &id.to_string(),
+            PutScheduledTransactionWrapper::new(scheduled_transaction),
+        )
+        .await?;
+        Ok(*response.data.scheduled_transaction)
+    }
+
+    pub async fn delete(
+        &self,
+        id: ScheduledTransactionId,
+    ) -> Result<ScheduledTransactionDetail, Error<DeleteScheduledTransactionError>> {
+        let response = scheduled_transactions_api::delete_scheduled_transaction(
+            self.configuration(),
+            self.budget_id(),
+            &id.to_string(),
+        )
+        .await?;
+        Ok(*response.data.scheduled_transaction)
+    }
+}
+
+impl Categories<'_> {
+    /// Lists the category groups and their categories.
+    pub async fn list(
+        &self,
+    ) -> Result<Vec<CategoryGroupWithCategories>, Error<GetCategoriesError>> {
+        let response =
+            categories_api::get_categories(self.configuration(), self.budget_id(), None).await?;
+        Ok(response.data.category_groups)
+    }
+
+    pub async fn get(&self, id: CategoryId) -> Result<Category, Error<GetCategoryByIdError>> {
+        let response = categories_api::get_category_by_id(
+            self.configuration(),
+            self.budget_id(),
+            &id.to_string(),
+        )
+        .await?;
+        Ok(*response.data.category)
+    }
+
+    pub async fn create(
+        &self,
+        category: NewCategory,
+    ) -> Result<Category, Error<CreateCategoryError>> {
+        let response = categories_api::create_category(
+            self.configuration(),
+            self.budget_id(),
+            PostCategoryWrapper::new(category),
+        )
+        .await?;
+        Ok(*response.data.category)
+    }
+
+    pub async fn update(
+        &self,
+        id: CategoryId,
+        category: SaveCategory,
+    ) -> Result<Category, Error<UpdateCategoryError>> {
+        let response = categories_api::update_category(
+            self.configuration(),
+            self.budget_id(),
+            &id.to_string(),
+            PatchCategoryWrapper::new(category),
+        )
+        .await?;
+        Ok(*response.data.category)
+    }
+}
+
+impl Payees<'_> {
+    pub async fn list(&self) -> Result<Vec<Payee>, Error<GetPayeesError>> {
+        let response = payees_api::get_payees(self.configuration(), self.budget_id(), None).await?;
+        Ok(response.data.payees)
+    }
+
+    pub async fn get(&self, id: PayeeId) -> Result<Payee, Error<GetPayeeByIdError>> {
+        let response =
+            payees_api::get_payee_by_id(self.configuration(), self.budget_id(), &id.to_string())
+                .await?;
+        Ok(*response.data.payee)
+    }
+
+    pub async fn update(
+        &self,
+        id: PayeeId,
+        payee: SavePayee,
+    ) -> Result<Payee, Error<UpdatePayeeError>> {
+        let response = payees_api::update_payee(
+            self.configuration(),
+            self.budget_id(),
+            &id.to_string(),
+            PatchPayeeWrapper::new(payee),
+        )
+        .await?;
+        Ok(*response.data.payee)
+    }
+}
+
+impl Months<'_> {
+    pub async fn list(&self) -> Result<Vec<MonthSummary>, Error<GetBudgetMonthsError>> {
+        let response =
+            months_api::get_budget_months(self.configuration(), self.budget_id(), None).await?;
+        Ok(response.data.months)
+    }
+
+    /// Returns a month, given as `YYYY-MM-01` or `current`.
+    pub async fn get(&self, month: &str) -> Result<MonthDetail, Error<GetBudgetMonthError>> {
+        let response =
+            months_api::get_budget_month(self.configuration(), self.budget_id(), month.to_owned())
+                .await?;
+        Ok(*response.data.month)
+    }
+}
+
+impl MoneyMovements<'_> {
+    /// Lists the money moved between categories.
+    pub async fn list(&self) -> Result<Vec<MoneyMovement>, Error<GetMoneyMovementsError>> {
+        let response =
+            money_movements_api::get_money_movements(self.configuration(), self.budget_id())
+                .await?;
+        Ok(response.data.money_movements)
+    }
+
+    /// Lists the money moved in a month, given as `YYYY-MM-01` or `current`.
+    pub async fn by_month(
+        &self,
+        month: &str,
+    ) -> Result<Vec<MoneyMovement>, Error<GetMoneyMovementsByMonthError>> {
+        let response = money_movements_api::get_money_movements_by_month(
+            self.configuration(),
+            self.budget_id(),
+            month.to_owned(),
+        )
+        .await?;
+        Ok(response.data.money_movements)
+    }
+
+    /// Lists the groups money movements made together belong to.
+    pub async fn groups(
+        &self,
+    ) -> Result<Vec<MoneyMovementGroup>, Error<GetMoneyMovementGroupsError>> {
+        let response =
+            money_movements_api::get_money_movement_groups(self.configuration(), self.budget_id())
+                .await?;
+        Ok(response.data.money_movement_groups)
+    }
+
+    /// Lists the money movement groups of a month, given as `YYYY-MM-01` or
+    /// `current`.
+    pub async fn groups_by_month(
+        &self,
+        month: &str,
+    ) -> Result<Vec<MoneyMovementGroup>, Error<GetMoneyMovementGroupsByMonthError>> {
+        let response = money_movements_api::get_money_movement_groups_by_month(
+            self.configuration(),
+            self.budget_id(),
+            month.to_owned(),
+        )
+        .await?;
+        Ok(response.data.money_movement_groups)
+    }
+}
@@ -0,0 +1,136 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+
+macro_rules! uuid_id {
+    ($(#[$meta:meta])* $name:ident) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+        #[serde(transparent)]
+        pub struct $name(pub uuid::Uuid);
+
+        impl From<uuid::Uuid> for $name {
+            fn from(id: uuid::Uuid) -> Self {
+                $name(id)
+            }
+        }
+
+        impl From<$name> for uuid::Uuid {
+            fn from(id: $name) -> Self {
+                id.0
+            }
+        }
+
+        impl FromStr for $name {
+            type Err = uuid::Error;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                s.parse().map($name)
+            }
+        }
+
+        impl fmt::Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                self.0.fmt(f)
+            }
+        }
+    };
+}
+
+uuid_id!(
+    /// Id of an account.
+    AccountId
+);
+uuid_id!(
+    /// Id of a category.
+    CategoryId
+);
+uuid_id!(
+    /// Id of a payee.
+    PayeeId
+);
+uuid_id!(
+    /// Id of a scheduled transaction.
+    ScheduledTransactionId
+);
+
+/// Id of a budget: its UUID, or `last-used` or `default` to let YNAB pick one.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct BudgetId(String);
+
+impl BudgetId {
+    /// The last budget used by the token's user.
+    pub fn last_used() -> Self {
+        BudgetId("last-used".to_owned())
+    }
+
+    /// The default budget of an OAuth application, when it has one.
+    pub fn default_budget() -> Self {
+        BudgetId("default".to_owned())
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl From<uuid::Uuid> for BudgetId {
+    fn from(id: uuid::Uuid) -> Self {
+        BudgetId(id.to_string())
+    }
+}
+
+impl FromStr for BudgetId {
+    type Err = uuid::Error;
+
+    /// Parses a UUID, `last-used` or `default`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "last-used" | "default" => Ok(BudgetId(s.to_owned())),
+            _ => s.parse::<uuid::Uuid>().map(BudgetId::from),
+        }
+    }
+}
+
+impl fmt::Display for BudgetId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+/// Id of a transaction. Usually a UUID, but the ids of upcoming occurrences of
+/// scheduled transactions are suffixed with their date.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct TransactionId(String);
+
+impl TransactionId {
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl From<String> for TransactionId {
+    fn from(id: String) -> Self {
+        TransactionId(id)
+    }
+}
+
+impl From<&str> for TransactionId {
+    fn from(id: &str) -> Self {
+        TransactionId(id.to_owned())
+    }
+}
+
+impl From<uuid::Uuid> for TransactionId {
+    fn from(id: uuid::Uuid) -> Self {
+        TransactionId(id.to_string())
+    }
+}
+
+impl fmt::Display for TransactionId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
@@ -0,0 +1,341 @@
+use std::collections::BTreeMap;
+use std::io;
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use super::{AccountId, BudgetId, CategoryId, PayeeId, TransactionId, YnabClient};
+use crate::apis::budgets_api::{self, GetBudgetByIdError};
+use crate::apis::Error;
+use crate::models::{
+    Account, BudgetDetail, Category, CategoryGroup, Payee, SubTransaction, TransactionSummary,
+};
+
+/// A local copy of a budget's accounts, payees, categories and transactions.
+///
+/// Each [`refresh`](Self::refresh) is a single request for what changed since
+/// the previous one, so reads are served locally instead of spending the rate
+/// limit on list requests. Deleted entities are dropped as their tombstones
+/// arrive.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BudgetMirror {
+    budget_id: BudgetId,
+    server_knowledge: Option<i64>,
+    accounts: BTreeMap<String, Account>,
+    payees: BTreeMap<String, Payee>,
+    category_groups: BTreeMap<String, CategoryGroup>,
+    categories: BTreeMap<String, Category>,
+    transactions: BTreeMap<String, TransactionSummary>,
+    subtransactions: BTreeMap<String, SubTransaction>,
+}
+
+/// Upserts the changed entities into a collection, removing deleted ones.
+fn merge<T>(
+    items: &mut BTreeMap<String, T>,
+    changes: Option<Vec<T>>,
+    key: impl Fn(&T) -> String,
+    deleted: impl Fn(&T) -> bool,
+) -> usize {
+    let changes = changes.unwrap_or_default();
+    let count = changes.len();
+
+    for item in changes {
+        if deleted(&item) {
+            items.remove(&key(&item));
+        } else {
+            items.insert(key(&item), item);
+        }
+    }
+
+    count
+}
+
+impl BudgetMirror {
+    /// An empty mirror of a budget, filled by its first refresh. Use the
+    /// budget's id rather than `last-used` or `default`, which may point to
+    /// another budget later.
+    pub fn new(budget_id: impl Into<BudgetId>) -> Self {
+        BudgetMirror {
+            budget_id: budget_id.into(),
+            server_knowledge: None,
+            accounts: BTreeMap::new(),
+            payees: BTreeMap::new(),
+            category_groups: BTreeMap::new(),
+            categories: BTreeMap::new(),
+            transactions: BTreeMap::new(),
+            subtransactions: BTreeMap::new(),
+        }
+    }
+
+    /// Loads a mirror saved to a file, or an empty one when the file does not
+    /// exist or mirrors another budget.
+    pub fn load(path: impl AsRef<Path>, budget_id: impl Into<BudgetId>) -> io::Result<Self> {
+        let budget_id = budget_id.into();
+
+        let mirror: BudgetMirror = match std::fs::read_to_string(path) {
+            Ok(json) => serde_json::from_str(&json)?,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(Self::new(budget_id)),
+            Err(e) => return Err(e),
+        };
+
+        if mirror.budget_id != budget_id {
+            return Ok(Self::new(budget_id));
+        }
+
+        Ok(mirror)
+    }
+
+    /// Writes the mirror next to `path` and renames it into place, so a crash
+    /// never leaves a truncated cache behind.
+    pub fn save(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        let path = path.as_ref();
+        let mut tmp = path.as_os_str().to_owned();
+        tmp.push(".tmp");
+
+        std::fs::write(&tmp, serde_json::to_string(self)?)?;
+        std::fs::rename(&tmp, path)
+    }
+
+    pub fn budget_id(&self) -> &BudgetId {
+        &self.budget_id
+    }
+
+    /// The server knowledge of the last refresh, `None` before the first.
+    pub fn server_knowledge(&self) -> Option<i64> {
+        self.server_knowledge
+    }
+
+    /// Fetches the changes since the last refresh, or the whole budget on the
+    /// first one. Returns the number of entities changed.
+    pub async fn refresh(
+        &mut self,
+        client: &YnabClient,
+    ) -> Result<usize, Error<GetBudgetByIdError>> {
+        let response = budgets_api::get_budget_by_id(
+            client.configuration(),
+            self.budget_id.as_str(),
+            self.server_knowledge,
+        )
+        .await?;
+
+        Ok(self.apply(*response.data.budget, response.data.server_knowledge))
+    }
+
+    /// Applies a budget delta fetched with this mirror's server knowledge.
+    pub fn apply(&mut self, budget: BudgetDetail, server_knowledge: i64) -> usize {
+        let changed = merge(
+            &mut self.accounts,
+            budget.accounts,
+            |a| a.id.to_string(),
+            |a| a.deleted,
+        ) + merge(
+            &mut self.payees,
+            budget.payees,
+            |p| p.id.to_string(),
+            |p| p.deleted,
+        ) + merge(
+            &mut self.category_groups,
+            budget.category_groups,
+            |g| g.id.to_string(),
+            |g| g.deleted,
+        ) + merge(
+            &mut self.categories,
+            budget.categories,
+            |c| c.id.to_string(),
+            |c| c.deleted,
+        ) + merge(
+            &mut self.transactions,
+            budget.transactions,
+            |t| t.id.clone(),
+            |t| t.deleted,
+        ) + merge(
+            &mut self.subtransactions,
+            budget.subtransactions,
+            |s| s.id.clone(),
+            |s| s.deleted,
+        );
+
+        self.server_knowledge = Some(server_knowledge);
+        changed
+    }
+
+    pub fn accounts(&self) -> impl Iterator<Item = &Account> {
+        self.accounts.values()
+    }
+
+    pub fn account(&self, id: AccountId) -> Option<&Account> {
+        self.accounts.get(&id.to_string())
+    }
+
+    pub fn payees(&self) -> impl Iterator<Item = &Payee> {
+        self.payees.values()
+    }
+
+    pub fn payee(&self, id: PayeeId) -> Option<&Payee> {
+        self.payees.get(&id.to_string())
+    }
+
+    pub fn category_groups(&self) -> impl Iterator<Item = &CategoryGroup> {
+        self.category_groups.values()
+    }
+
+    pub fn categories(&self) -> impl Iterator<Item = &Category> {
+        self.categories.values()
+    }
+
+    pub fn category(&self, id: CategoryId) -> Option<&Category> {
+        self.categories.get(&id.to_string())
+    }
+
+    pub fn transactions(&self) -> impl Iterator<Item = &TransactionSummary> {
+        self.transactions.values()
+    }
+
+    pub fn transaction(&self, id: &TransactionId) -> Option<&TransactionSummary> {
+        self.transactions.get(id.as_str())
+    }
+
+    pub fn transactions_by_account(
+        &self,
+        account: AccountId,
+    ) -> impl Iterator<Item = &TransactionSummary> {
+        self.transactions
+            .values()
+            .filter(move |t| t.account() == account)
+    }
+
+    /// The splits of a transaction.
+    pub fn subtransactions(&self, id: &TransactionId) -> impl Iterator<Item = &SubTransaction> {
+        let id = id.as_str().to_owned();
+        self.subtransactions
+            .values()
+            .filter(move |s| s.transaction_id == id)
+    }
+
+    /// The name of a transaction's payee.
+    pub fn payee_name(&self, transaction: &TransactionSummary) -> Option<&str> {
+        Some(self.payee(transaction.payee()?)?.name.as_str())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::TransactionClearedStatus;
+    use uuid::Uuid;
+
+    const ACCOUNT: Uuid = Uuid::from_u128(1);
+
+    fn budget(transactions: Vec<TransactionSummary>) -> BudgetDetail {
+        BudgetDetail {
+            transactions: Some(transactions),
+            ..BudgetDetail::new(Uuid::from_u128(100), "Budget".to_string())
+        }
+    }
+
+    fn transaction(id: &str, amount: i64, deleted: bool) -> TransactionSummary {
+        TransactionSummary::new(
+            id.to_string(),
+            "2026-01-01".to_string(),
+            amount,
+            TransactionClearedStatus::Cleared,
+            true,
+            ACCOUNT,
+            deleted,
+        )
+    }
+
+    fn amounts(mirror: &BudgetMirror) -> Vec<(&str, i64)> {
+        mirror
+            .transactions()
+            .map(|t| (t.id.as_str(), t.amount))
+            .collect()
+    }
+
+    #[test]
+    fn applies_the_first_refresh_and_advances_the_server_knowledge() {
+        let mut mirror = BudgetMirror::new(Uuid::from_u128(100));
+        assert_eq!(mirror.server_knowledge(), None);
+
+        let changed = mirror.apply(
+            budget(vec![
+                transaction("a", 1_000, false),
+                transaction("b", 2_000, false),
+            ]),
+            10,
+        );
+
+        assert_eq!(changed, 2);
+        assert_eq!(mirror.server_knowledge(), Some(10));
+        assert_eq!(amounts(&mirror), [("a", 1_000), ("b", 2_000)]);
+
+        mirror.apply(budget(vec![]), 12);
+
+        assert_eq!(mirror.server_knowledge(), Some(12));
+        assert_eq!(amounts(&mirror), [("a", 1_000), ("b", 2_000)]);
+    }
+
+    #[test]
+    fn replaces_updated_entries() {
+        let mut mirror = BudgetMirror::new(Uuid::from_u128(100));
+        mirror.apply(
+            budget(vec![
+                transaction("a", 1_000, false),
+                transaction("b", 2_000, false),
+            ]),
+            10,
+        );
+
+        mirror.apply(budget(vec![transaction("a", 1_500, false)]), 11);
+
+        assert_eq!(amounts(&mirror), [("a", 1_500), ("b", 2_000)]);
+    }
+
+    #[test]
+    fn removes_deleted_entries() {
+        let mut mirror = BudgetMirror::new(Uuid::from_u128(100));
+        mirror.apply(
+            budget(vec![
+                transaction("a", 1_000, false),
+                transaction("b", 2_000, false),
+            ]),
+            10,
+        );
+
+        let changed = mirror.apply(
+            budget(vec![
+                transaction("a", 1_000, true),
+                transaction("c", 3_000, true),
+            ]),
+            11,
+        );
+
+        assert_eq!(changed, 2);
+        assert_eq!(amounts(&mirror), [("b", 2_000)]);
+        assert!(mirror.transaction(&TransactionId::from("a")).is_none());
+    }
+
+    #[test]
+    fn saves_and_loads_a_mirror() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("mirror.json");
+        let budget_id = Uuid::from_u128(100);
+
+        let mut mirror = BudgetMirror::new(budget_id);
+        mirror.apply(budget(vec![transaction("a", 1_000, false)]), 10);
+        mirror.save(&path).unwrap();
+
+        let loaded = BudgetMirror::load(&path, budget_id).unwrap();
+
+        assert_eq!(loaded.server_knowledge(), Some(10));
+        assert_eq!(amounts(&loaded), [("a", 1_000)]);
+        assert!(!dir.path().join("mirror.json.tmp").exists());
+
+        let other = BudgetMirror::load(&path, Uuid::from_u128(200)).unwrap();
+        assert_eq!(other.server_knowledge(), None);
+        assert_eq!(other.transactions().count(), 0);
+
+        let missing = BudgetMirror::load(dir.path().join("missing.json"), budget_id).unwrap();
+        assert_eq!(missing.server_knowledge(), None);
+    }
+}
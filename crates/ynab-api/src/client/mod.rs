@@ -0,0 +1,95 @@
+//! A typed client over the generated [`apis`](crate::apis) functions.
+//!
+//! ```no_run
+//! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! use ynab_api::client::{BudgetId, YnabClient};
+//!
+//! let client = YnabClient::new("personal-access-token");
+//! let budget = client.budget(BudgetId::last_used());
+//!
+//! for account in budget.accounts().list().await? {
+//!     println!("{}: {}", account.name, account.balance_money());
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+mod budget;
+mod ids;
+mod mirror;
+mod models;
+mod money;
+mod transaction;
+
+pub use budget::{
+    Accounts, BudgetHandle, Categories, MoneyMovements, Months, Payees, ScheduledTransactions,
+    Transactions,
+};
+pub use ids::{AccountId, BudgetId, CategoryId, PayeeId, ScheduledTransactionId, TransactionId};
+pub use mirror::BudgetMirror;
+pub use money::Money;
+pub use transaction::TransactionParams;
+
+use crate::apis::budgets_api::{self, GetBudgetsError};
+use crate::apis::configuration::Configuration;
+use crate::apis::user_api::{self, GetUserError};
+use crate::apis::Error;
+use crate::models::{BudgetSummary, User};
+
+/// A YNAB API client authorized with one token.
+#[derive(Debug, Clone)]
+pub struct YnabClient {
+    configuration: Configuration,
+}
+
+impl YnabClient {
+    /// Creates a client authorized with a personal access token or an OAuth
+    /// access token.
+    pub fn new(access_token: impl Into<String>) -> Self {
+        let mut configuration = Configuration::new();
+        configuration.bearer_access_token = Some(access_token.into());
+        YnabClient { configuration }
+    }
+
+    pub fn with_configuration(configuration: Configuration) -> Self {
+        YnabClient { configuration }
+    }
+
+    /// The configuration to call the generated functions with directly.
+    pub fn configuration(&self) -> &Configuration {
+        &self.configuration
+    }
+
+    /// Requests left in the current rate limit window.
+    pub fn remaining_requests(&self) -> u32 {
+        self.configuration.remaining_requests()
+    }
+
+    pub async fn user(&self) -> Result<User, Error<GetUserError>> {
+        let response = user_api::get_user(&self.configuration).await?;
+        Ok(*response.data.user)
+    }
+
+    pub async fn budgets(&self) -> Result<Vec<BudgetSummary>, Error<GetBudgetsError>> {
+        let response = budgets_api::get_budgets(&self.configuration, None).await?;
+        Ok(response.data.budgets)
+    }
+
+    /// Returns the default budget of an OAuth application, or else the first
+    /// budget of the user.
+    pub async fn default_budget(&self) -> Result<Option<BudgetSummary>, Error<GetBudgetsError>> {
+        let data = budgets_api::get_budgets(&self.configuration, None)
+            .await?
+            .data;
+
+        Ok(match data.default_budget {
+            Some(budget) => Some(*budget),
+            None => data.budgets.into_iter().next(),
+        })
+    }
+
+    /// Returns a handle to the endpoints of a budget.
+    pub fn budget(&self, id: impl Into<BudgetId>) -> BudgetHandle<'_> {
+        BudgetHandle::new(self, id.into())
+    }
+}
@@ -0,0 +1,114 @@
+//! Typed accessors for the generated models.
+
+use super::{
+    AccountId, BudgetId, CategoryId, Money, PayeeId, ScheduledTransactionId, TransactionId,
+};
+use crate::models::{
+    Account, BudgetSummary, Category, CurrencyFormat, Payee, ScheduledTransactionDetail,
+    TransactionDetail, TransactionSummary,
+};
+
+impl BudgetSummary {
+    pub fn budget_id(&self) -> BudgetId {
+        self.id.into()
+    }
+
+    /// The currency format, when the budget has one.
+    pub fn currency(&self) -> Option<&CurrencyFormat> {
+        self.currency_format.as_ref()?.as_deref()
+    }
+}
+
+impl Account {
+    pub fn account_id(&self) -> AccountId {
+        self.id.into()
+    }
+
+    pub fn balance_money(&self) -> Money {
+        Money::from_milliunits(self.balance)
+    }
+
+    pub fn cleared_balance_money(&self) -> Money {
+        Money::from_milliunits(self.cleared_balance)
+    }
+}
+
+impl TransactionDetail {
+    pub fn transaction_id(&self) -> TransactionId {
+        self.id.clone().into()
+    }
+
+    pub fn account(&self) -> AccountId {
+        self.account_id.into()
+    }
+
+    pub fn amount_money(&self) -> Money {
+        Money::from_milliunits(self.amount)
+    }
+
+    /// The payee name, flattened from the nullable field of the same name.
+    pub fn payee_name(&self) -> Option<&str> {
+        self.payee_name.as_ref()?.as_deref()
+    }
+
+    pub fn payee(&self) -> Option<PayeeId> {
+        self.payee_id.flatten().map(PayeeId)
+    }
+
+    /// The memo, flattened from the nullable field of the same name.
+    pub fn memo(&self) -> Option<&str> {
+        self.memo.as_ref()?.as_deref()
+    }
+
+    pub fn category(&self) -> Option<CategoryId> {
+        self.category_id.flatten().map(CategoryId)
+    }
+}
+
+impl TransactionSummary {
+    pub fn transaction_id(&self) -> TransactionId {
+        self.id.clone().into()
+    }
+
+    pub fn account(&self) -> AccountId {
+        self.account_id.into()
+    }
+
+    pub fn amount_money(&self) -> Money {
+        Money::from_milliunits(self.amount)
+    }
+
+    pub fn payee(&self) -> Option<PayeeId> {
+        self.payee_id.flatten().map(PayeeId)
+    }
+
+    pub fn category(&self) -> Option<CategoryId> {
+        self.category_id.flatten().map(CategoryId)
+    }
+}
+
+impl ScheduledTransactionDetail {
+    pub fn scheduled_transaction_id(&self) -> ScheduledTransactionId {
+        self.id.into()
+    }
+
+    pub fn amount_money(&self) -> Money {
+        Money::from_milliunits(self.amount)
+    }
+}
+
+impl Category {
+    pub fn category_id(&self) -> CategoryId {
+        self.id.into()
+    }
+
+    pub fn balance_money(&self) -> Money {
+        Money::from_milliunits(self.balance)
+    }
+}
+
+impl Payee {
+    pub fn payee_id(&self) -> PayeeId {
+        self.id.into()
+    }
+}
@@ -0,0 +1,237 @@
+use crate::models::CurrencyFormat;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::iter::Sum;
+use std::ops::{Add, AddAssign, Neg, Sub, SubAssign};
+
+/// An amount in milliunits of a budget's currency, the way YNAB represents
+/// money: `12340` is 12.34.
+#[derive(
+    Debug, Clone, Copy, Default, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize,
+)]
+#[serde(transparent)]
+pub struct Money(i64);
+
+impl Money {
+    pub const ZERO: Money = Money(0);
+
+    pub const fn from_milliunits(milliunits: i64) -> Self {
+        Money(milliunits)
+    }
+
+    pub const fn milliunits(self) -> i64 {
+        self.0
+    }
+
+    /// Converts an amount in currency units, rounded to the nearest milliunit.
+    pub fn from_units(units: f64) -> Self {
+        Money((units * 1000.0).round() as i64)
+    }
+
+    pub fn to_units(self) -> f64 {
+        self.0 as f64 / 1000.0
+    }
+
+    pub const fn is_zero(self) -> bool {
+        self.0 == 0
+    }
+
+    /// Formats the amount the way YNAB shows it in a budget with this currency
+    /// format, e.g. `$1,234.56` or `1.234,56€`.
+    pub fn format(self, format: &CurrencyFormat) -> String {
+        let digits = format.decimal_digits.clamp(0, 3) as u32;
+        let divisor = 10i64.pow(3 - digits);
+        let rounded = (self.0.unsigned_abs() as i64 + divisor / 2) / divisor;
+        let scale = 10i64.pow(digits);
+
+        let mut number = group_thousands(rounded / scale, &format.group_separator);
+
+        if digits > 0 {
+            number.push_str(&format.decimal_separator);
+            number.push_str(&format!(
+                "{:0width$}",
+                rounded % scale,
+                width = digits as usize
+            ));
+        }
+
+        let sign = if self.0 < 0 && rounded != 0 { "-" } else { "" };
+
+        match (format.display_symbol, format.symbol_first) {
+            (false, _) => format!("{sign}{number}"),
+            (true, true) => format!("{sign}{}{number}", format.currency_symbol),
+            (true, false) => format!("{sign}{number}{}", format.currency_symbol),
+        }
+    }
+}
+
+fn group_thousands(value: i64, separator: &str) -> String {
+    let digits = value.to_string();
+    let mut grouped = String::new();
+
+    for (i, digit) in digits.chars().enumerate() {
+        if i > 0 && (digits.len() - i).is_multiple_of(3) {
+            grouped.push_str(separator);
+        }
+        grouped.push(digit);
+    }
+
+    grouped
+}
+
+/// Shows the amount in units with three decimals, e.g. `-12.340`.
+impl fmt::Display for Money {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let sign = if self.0 < 0 { "-" } else { "" };
+        let abs = self.0.unsigned_abs();
+        write!(f, "{sign}{}.{:03}", abs / 1000, abs % 1000)
+    }
+}
+
+impl From<i64> for Money {
+    fn from(milliunits: i64) -> Self {
+        Money(milliunits)
+    }
+}
+
+impl From<Money> for i64 {
+    fn from(money: Money) -> Self {
+        money.0
+    }
+}
+
+impl Add for Money {
+    type Output = Money;
+
+    fn add(self, rhs: Money) -> Money {
+        Money(self.0 + rhs.0)
+    }
+}
+
+impl AddAssign for Money {
+    fn add_assign(&mut self, rhs: Money) {
+        self.0 += rhs.0;
+    }
+}
+
+impl Sub for Money {
+    type Output = Money;
+
+    fn sub(self, rhs: Money) -> Money {
+        Money(self.0 - rhs.0)
+    }
+}
+
+impl SubAssign for Money {
+    fn sub_assign(&mut self, rhs: Money) {
+        self.0 -= rhs.0;
+    }
+}
+
+impl Neg for Money {
+    type Output = Money;
+
+    fn neg(self) -> Money {
+        Money(-self.0)
+    }
+}
+
+impl Sum for Money {
+    fn sum<I: Iterator<Item = Money>>(iter: I) -> Money {
+        Money(iter.map(|m| m.0).sum())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn currency(
+        iso_code: &str,
+        decimal_digits: i32,
+        decimal_separator: &str,
+        group_separator: &str,
+        symbol: &str,
+        symbol_first: bool,
+    ) -> CurrencyFormat {
+        CurrencyFormat {
+            iso_code: iso_code.to_string(),
+            example_format: String::new(),
+            decimal_digits,
+            decimal_separator: decimal_separator.to_string(),
+            symbol_first,
+            group_separator: group_separator.to_string(),
+            currency_symbol: symbol.to_string(),
+            display_symbol: true,
+        }
+    }
+
+    #[test]
+    fn formats_amounts_in_the_budget_currency() {
+        let usd = currency("USD", 2, ".", ",", "$", true);
+        let eur = currency("EUR", 2, ",", ".", "€", false);
+        let jpy = currency("JPY", 0, ".", ",", "¥", true);
+        let bhd = currency("BHD", 3, ".", ",", "BD", true);
+        let chf = currency("CHF", 2, ".", "'", "CHF ", true);
+
+        for (format, milliunits, expected) in [
+            (&usd, 0, "$0.00"),
+            (&usd, 5, "$0.01"),
+            (&usd, 4, "$0.00"),
+            (&usd, 12_340, "$12.34"),
+            (&usd, 999, "$1.00"),
+            (&usd, 1_234_560, "$1,234.56"),
+            (&usd, 1_234_567_890, "$1,234,567.89"),
+            (&usd, -1_234_560, "-$1,234.56"),
+            // rounds to zero without a negative sign
+            (&usd, -4, "$0.00"),
+            (&eur, 1_234_560, "1.234,56€"),
+            (&eur, -100_000, "-100,00€"),
+            (&jpy, 1_234_500, "¥1,235"),
+            (&jpy, 999_499, "¥999"),
+            (&bhd, 1_234_567, "BD1,234.567"),
+            (&chf, 1_000_000_000, "CHF 1'000'000.00"),
+        ] {
+            assert_eq!(
+                Money::from_milliunits(milliunits).format(format),
+                expected,
+                "{milliunits} in {}",
+                format.iso_code
+            );
+        }
+    }
+
+    #[test]
+    fn formats_without_the_symbol_when_hidden() {
+        let format = CurrencyFormat {
+            display_symbol: false,
+            ..currency("USD", 2, ".", ",", "$", true)
+        };
+
+        assert_eq!(
+            Money::from_milliunits(-1_234_560).format(&format),
+            "-1,234.56"
+        );
+    }
+
+    #[test]
+    fn groups_thousands() {
+        for (value, expected) in [
+            (0, "0"),
+            (999, "999"),
+            (1_000, "1 000"),
+            (12_345, "12 345"),
+            (123_456, "123 456"),
+            (1_234_567, "1 234 567"),
+        ] {
+            assert_eq!(group_thousands(value, " "), expected);
+        }
+    }
+
+    #[test]
+    fn displays_three_decimals() {
+        assert_eq!(Money::from_milliunits(12_340).to_string(), "12.340");
+        assert_eq!(Money::from_milliunits(-5).to_string(), "-0.005");
+        assert_eq!(Money::from_units(-12.3456), Money::from_milliunits(-12_346));
+    }
+}
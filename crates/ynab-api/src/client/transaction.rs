@@ -0,0 +1,139 @@
+//! Typed parameters for writing transactions.
+
+use super::{AccountId, CategoryId, Money, PayeeId};
+use crate::models::{
+    ExistingTransaction, NewTransaction, TransactionClearedStatus, TransactionFlagColor,
+};
+
+/// The fields of a transaction to create or update. Fields left unset are
+/// not sent, so an update only changes the ones that are set.
+///
+/// ```
+/// use ynab_api::client::{AccountId, Money, TransactionParams};
+/// use ynab_api::models::TransactionClearedStatus;
+///
+/// let account = AccountId(uuid::Uuid::nil());
+/// let params = TransactionParams::new()
+///     .account(account)
+///     .date("2026-01-31")
+///     .amount(Money::from_milliunits(-12_340))
+///     .payee_name("Groceries")
+///     .cleared(TransactionClearedStatus::Cleared);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct TransactionParams {
+    account: Option<AccountId>,
+    date: Option<String>,
+    amount: Option<Money>,
+    payee: Option<PayeeId>,
+    payee_name: Option<String>,
+    category: Option<CategoryId>,
+    memo: Option<String>,
+    cleared: Option<TransactionClearedStatus>,
+    approved: Option<bool>,
+    flag_color: Option<TransactionFlagColor>,
+    import_id: Option<String>,
+}
+
+impl TransactionParams {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn account(mut self, account: AccountId) -> Self {
+        self.account = Some(account);
+        self
+    }
+
+    /// The date of the transaction, as `YYYY-MM-DD`.
+    pub fn date(mut self, date: impl Into<String>) -> Self {
+        self.date = Some(date.into());
+        self
+    }
+
+    pub fn amount(mut self, amount: Money) -> Self {
+        self.amount = Some(amount);
+        self
+    }
+
+    pub fn payee(mut self, payee: PayeeId) -> Self {
+        self.payee = Some(payee);
+        self
+    }
+
+    /// The name of the payee, which YNAB resolves to an existing payee or
+    /// creates when no payee id is set.
+    pub fn payee_name(mut self, name: impl Into<String>) -> Self {
+        self.payee_name = Some(name.into());
+        self
+    }
+
+    pub fn category(mut self, category: CategoryId) -> Self {
+        self.category = Some(category);
+        self
+    }
+
+    pub fn memo(mut self, memo: impl Into<String>) -> Self {
+        self.memo = Some(memo.into());
+        self
+    }
+
+    pub fn cleared(mut self, cleared: TransactionClearedStatus) -> Self {
+        self.cleared = Some(cleared);
+        self
+    }
+
+    pub fn approved(mut self, approved: bool) -> Self {
+        self.approved = Some(approved);
+        self
+    }
+
+    pub fn flag_color(mut self, flag_color: TransactionFlagColor) -> Self {
+        self.flag_color = Some(flag_color);
+        self
+    }
+
+    /// The import id, which YNAB uses to skip duplicates. Only sent when
+    /// creating a transaction, as it cannot be changed afterwards.
+    pub fn import_id(mut self, import_id: impl Into<String>) -> Self {
+        self.import_id = Some(import_id.into());
+        self
+    }
+}
+
+impl From<TransactionParams> for NewTransaction {
+    fn from(params: TransactionParams) -> Self {
+        NewTransaction {
+            account_id: params.account.map(Into::into),
+            date: params.date,
+            amount: params.amount.map(Money::milliunits),
+            payee_id: params.payee.map(|p| Some(p.into())),
+            payee_name: params.payee_name.map(Some),
+            category_id: params.category.map(|c| Some(c.into())),
+            memo: params.memo.map(Some),
+            cleared: params.cleared,
+            approved: params.approved,
+            flag_color: params.flag_color.map(Some),
+            subtransactions: None,
+            import_id: params.import_id.map(Some),
+        }
+    }
+}
+
+impl From<TransactionParams> for ExistingTransaction {
+    fn from(params: TransactionParams) -> Self {
+        ExistingTransaction {
+            account_id: params.account.map(Into::into),
+            date: params.date,
+            amount: params.amount.map(Money::milliunits),
+            payee_id: params.payee.map(|p| Some(p.into())),
+            payee_name: params.payee_name.map(Some),
+            category_id: params.category.map(|c| Some(c.into())),
+            memo: params.memo.map(Some),
+            cleared: params.cleared,
+            approved: params.approved,
+            flag_color: params.flag_color.map(Some),
+            subtransactions: None,
+        }
+    }
+}
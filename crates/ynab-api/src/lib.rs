@@ -1,5 +1,6 @@
 #![allow(unused_imports)]
 #![allow(clippy::too_many_arguments)]
+#![allow(clippy::derivable_impls, clippy::manual_map, clippy::empty_docs)]
 
 extern crate serde_repr;
 extern crate serde;
@@ -8,4 +9,5 @@ extern crate url;
 extern crate reqwest;
 
 pub mod apis;
+pub mod client;
 pub mod models;
@@ -66,6 +66,18 @@ pub mod hybrid_transactions_response;
 pub use self::hybrid_transactions_response::HybridTransactionsResponse;
 pub mod hybrid_transactions_response_data;
 pub use self::hybrid_transactions_response_data::HybridTransactionsResponseData;
+pub mod money_movement;
+pub use self::money_movement::MoneyMovement;
+pub mod money_movement_group;
+pub use self::money_movement_group::MoneyMovementGroup;
+pub mod money_movement_groups_response;
+pub use self::money_movement_groups_response::MoneyMovementGroupsResponse;
+pub mod money_movement_groups_response_data;
+pub use self::money_movement_groups_response_data::MoneyMovementGroupsResponseData;
+pub mod money_movements_response;
+pub use self::money_movements_response::MoneyMovementsResponse;
+pub mod money_movements_response_data;
+pub use self::money_movements_response_data::MoneyMovementsResponseData;
 pub mod month_detail;
 pub use self::month_detail::MonthDetail;
 pub mod month_detail_response;
@@ -78,6 +90,8 @@ pub mod month_summaries_response_data;
 pub use self::month_summaries_response_data::MonthSummariesResponseData;
 pub mod month_summary;
 pub use self::month_summary::MonthSummary;
+pub mod new_category;
+pub use self::new_category::NewCategory;
 pub mod new_transaction;
 pub use self::new_transaction::NewTransaction;
 pub mod patch_category_wrapper;
@@ -110,10 +124,14 @@ pub mod payees_response_data;
 pub use self::payees_response_data::PayeesResponseData;
 pub mod post_account_wrapper;
 pub use self::post_account_wrapper::PostAccountWrapper;
+pub mod post_category_wrapper;
+pub use self::post_category_wrapper::PostCategoryWrapper;
 pub mod post_scheduled_transaction_wrapper;
 pub use self::post_scheduled_transaction_wrapper::PostScheduledTransactionWrapper;
 pub mod post_transactions_wrapper;
 pub use self::post_transactions_wrapper::PostTransactionsWrapper;
+pub mod put_scheduled_transaction_wrapper;
+pub use self::put_scheduled_transaction_wrapper::PutScheduledTransactionWrapper;
 pub mod put_transaction_wrapper;
 pub use self::put_transaction_wrapper::PutTransactionWrapper;
 pub mod save_account;
@@ -0,0 +1,58 @@
+/*
+ * YNAB API Endpoints
+ *
+ * Our API uses a REST based design, leverages the JSON data format, and relies upon HTTPS for transport. We respond with meaningful HTTP response codes and if an error occurs, we include error details in the response body.  API Documentation is at https://api.ynab.com
+ *
+ * The version of the OpenAPI document: 1.72.1
+ * 
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct MoneyMovement {
+    #[serde(rename = "id")]
+    pub id: uuid::Uuid,
+    /// The month of the money movement in ISO format (e.g. 2024-01-01)
+    #[serde(rename = "month", default, with = "::serde_with::rust::double_option", skip_serializing_if = "Option::is_none")]
+    pub month: Option<Option<String>>,
+    /// The date and time the money movement took place in ISO format (e.g. 2024-01-01T12:00:00Z)
+    #[serde(rename = "moved_at", default, with = "::serde_with::rust::double_option", skip_serializing_if = "Option::is_none")]
+    pub moved_at: Option<Option<String>>,
+    #[serde(rename = "note", default, with = "::serde_with::rust::double_option", skip_serializing_if = "Option::is_none")]
+    pub note: Option<Option<String>>,
+    /// The id of the money movement group this movement belongs to
+    #[serde(rename = "money_movement_group_id", default, with = "::serde_with::rust::double_option", skip_serializing_if = "Option::is_none")]
+    pub money_movement_group_id: Option<Option<uuid::Uuid>>,
+    /// The id of the user who performed the money movement
+    #[serde(rename = "performed_by_user_id", default, with = "::serde_with::rust::double_option", skip_serializing_if = "Option::is_none")]
+    pub performed_by_user_id: Option<Option<uuid::Uuid>>,
+    /// The id of the category the money was moved from
+    #[serde(rename = "from_category_id", default, with = "::serde_with::rust::double_option", skip_serializing_if = "Option::is_none")]
+    pub from_category_id: Option<Option<uuid::Uuid>>,
+    /// The id of the category the money was moved to
+    #[serde(rename = "to_category_id", default, with = "::serde_with::rust::double_option", skip_serializing_if = "Option::is_none")]
+    pub to_category_id: Option<Option<uuid::Uuid>>,
+    /// The amount of the money movement in milliunits format
+    #[serde(rename = "amount")]
+    pub amount: i64,
+}
+
+impl MoneyMovement {
+    pub fn new(id: uuid::Uuid, amount: i64) -> MoneyMovement {
+        MoneyMovement {
+            id,
+            month: None,
+            moved_at: None,
+            note: None,
+            money_movement_group_id: None,
+            performed_by_user_id: None,
+            from_category_id: None,
+            to_category_id: None,
+            amount,
+        }
+    }
+}
+
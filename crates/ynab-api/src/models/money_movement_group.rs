@@ -0,0 +1,42 @@
+/*
+ * YNAB API Endpoints
+ *
+ * Our API uses a REST based design, leverages the JSON data format, and relies upon HTTPS for transport. We respond with meaningful HTTP response codes and if an error occurs, we include error details in the response body.  API Documentation is at https://api.ynab.com
+ *
+ * The version of the OpenAPI document: 1.72.1
+ * 
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct MoneyMovementGroup {
+    #[serde(rename = "id")]
+    pub id: uuid::Uuid,
+    /// The date and time the money movement group was created in ISO format (e.g. 2024-01-01T12:00:00Z)
+    #[serde(rename = "group_created_at")]
+    pub group_created_at: String,
+    /// The month of the money movement group in ISO format (e.g. 2024-01-01)
+    #[serde(rename = "month")]
+    pub month: String,
+    #[serde(rename = "note", default, with = "::serde_with::rust::double_option", skip_serializing_if = "Option::is_none")]
+    pub note: Option<Option<String>>,
+    /// The id of the user who performed the money movement group
+    #[serde(rename = "performed_by_user_id", default, with = "::serde_with::rust::double_option", skip_serializing_if = "Option::is_none")]
+    pub performed_by_user_id: Option<Option<uuid::Uuid>>,
+}
+
+impl MoneyMovementGroup {
+    pub fn new(id: uuid::Uuid, group_created_at: String, month: String) -> MoneyMovementGroup {
+        MoneyMovementGroup {
+            id,
+            group_created_at,
+            month,
+            note: None,
+            performed_by_user_id: None,
+        }
+    }
+}
+
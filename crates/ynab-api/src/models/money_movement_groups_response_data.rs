@@ -0,0 +1,31 @@
+/*
+ * YNAB API Endpoints
+ *
+ * Our API uses a REST based design, leverages the JSON data format, and relies upon HTTPS for transport. We respond with meaningful HTTP response codes and if an error occurs, we include error details in the response body.  API Documentation is at https://api.ynab.com
+ *
+ * The version of the OpenAPI document: 1.72.1
+ * 
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct MoneyMovementGroupsResponseData {
+    #[serde(rename = "money_movement_groups")]
+    pub money_movement_groups: Vec<models::MoneyMovementGroup>,
+    /// The knowledge of the server
+    #[serde(rename = "server_knowledge")]
+    pub server_knowledge: i64,
+}
+
+impl MoneyMovementGroupsResponseData {
+    pub fn new(money_movement_groups: Vec<models::MoneyMovementGroup>, server_knowledge: i64) -> MoneyMovementGroupsResponseData {
+        MoneyMovementGroupsResponseData {
+            money_movement_groups,
+            server_knowledge,
+        }
+    }
+}
+
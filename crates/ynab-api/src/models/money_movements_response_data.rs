@@ -0,0 +1,31 @@
+/*
+ * YNAB API Endpoints
+ *
+ * Our API uses a REST based design, leverages the JSON data format, and relies upon HTTPS for transport. We respond with meaningful HTTP response codes and if an error occurs, we include error details in the response body.  API Documentation is at https://api.ynab.com
+ *
+ * The version of the OpenAPI document: 1.72.1
+ * 
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct MoneyMovementsResponseData {
+    #[serde(rename = "money_movements")]
+    pub money_movements: Vec<models::MoneyMovement>,
+    /// The knowledge of the server
+    #[serde(rename = "server_knowledge")]
+    pub server_knowledge: i64,
+}
+
+impl MoneyMovementsResponseData {
+    pub fn new(money_movements: Vec<models::MoneyMovement>, server_knowledge: i64) -> MoneyMovementsResponseData {
+        MoneyMovementsResponseData {
+            money_movements,
+            server_knowledge,
+        }
+    }
+}
+
@@ -0,0 +1,42 @@
+/*
+ * YNAB API Endpoints
+ *
+ * Our API uses a REST based design, leverages the JSON data format, and relies upon HTTPS for transport. We respond with meaningful HTTP response codes and if an error occurs, we include error details in the response body.  API Documentation is at https://api.ynab.com
+ *
+ * The version of the OpenAPI document: 1.72.1
+ * 
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct NewCategory {
+    #[serde(rename = "name")]
+    pub name: String,
+    /// The category group the category is created in
+    #[serde(rename = "category_group_id")]
+    pub category_group_id: uuid::Uuid,
+    #[serde(rename = "note", default, with = "::serde_with::rust::double_option", skip_serializing_if = "Option::is_none")]
+    pub note: Option<Option<String>>,
+    /// The goal target amount in milliunits format.  If set, a monthly \"Needed for Spending\" goal is created for the category.
+    #[serde(rename = "goal_target", default, with = "::serde_with::rust::double_option", skip_serializing_if = "Option::is_none")]
+    pub goal_target: Option<Option<i64>>,
+    /// The goal target date in ISO format (e.g. 2016-12-01).
+    #[serde(rename = "goal_target_date", default, with = "::serde_with::rust::double_option", skip_serializing_if = "Option::is_none")]
+    pub goal_target_date: Option<Option<String>>,
+}
+
+impl NewCategory {
+    pub fn new(name: String, category_group_id: uuid::Uuid) -> NewCategory {
+        NewCategory {
+            name,
+            category_group_id,
+            note: None,
+            goal_target: None,
+            goal_target_date: None,
+        }
+    }
+}
+
@@ -0,0 +1,585 @@
+//! An in-process YNAB API server for tests.
+//!
+//! [`MockYnab`] serves the endpoints the sync uses (budgets, accounts and
+//! transactions) from memory, with server knowledge so delta requests only
+//! return what changed. Point a `Configuration`'s `base_path` at
+//! [`MockYnab::base_path`] and authorize with [`MockYnab::token`].
+
+use std::collections::HashMap;
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::{SocketAddr, TcpListener, TcpStream};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::{json, Value};
+use uuid::Uuid;
+use ynab_api::models::{
+    Account, AccountType, CurrencyFormat, ErrorDetail, ErrorResponse, Payee, TransactionDetail,
+    TransactionSummary,
+};
+
+const TOKEN: &str = "mock-ynab-token";
+/// Requests per hour reported in the `X-Rate-Limit` header, as YNAB does.
+const RATE_LIMIT: usize = 200;
+
+/// An entity with the server knowledge it last changed at.
+#[derive(Debug, Clone)]
+struct Versioned<T> {
+    knowledge: i64,
+    item: T,
+}
+
+#[derive(Debug)]
+struct State {
+    budget_id: Uuid,
+    currency: CurrencyFormat,
+    knowledge: i64,
+    accounts: Vec<Versioned<Account>>,
+    payees: Vec<Versioned<Payee>>,
+    transactions: Vec<Versioned<TransactionSummary>>,
+    requests: Vec<String>,
+}
+
+/// An HTTP response: status code and JSON body.
+type Response = (u16, Value);
+
+impl State {
+    fn bump(&mut self) -> i64 {
+        self.knowledge += 1;
+        self.knowledge
+    }
+
+    fn add_account(&mut self, name: &str, r#type: AccountType, balance: i64) -> Account {
+        let knowledge = self.bump();
+        let account = Account::new(
+            Uuid::new_v4(),
+            name.to_string(),
+            r#type,
+            false,
+            false,
+            balance,
+            balance,
+            0,
+            None,
+            false,
+        );
+
+        self.accounts.push(Versioned {
+            knowledge,
+            item: account.clone(),
+        });
+        account
+    }
+
+    /// Returns the payee with a name, creating it like YNAB does for a new
+    /// `payee_name`.
+    fn payee_id(&mut self, name: &str) -> Uuid {
+        if let Some(payee) = self.payees.iter().find(|p| p.item.name == name) {
+            return payee.item.id;
+        }
+
+        let knowledge = self.bump();
+        let payee = Payee::new(Uuid::new_v4(), name.to_string(), false);
+        let id = payee.id;
+        self.payees.push(Versioned {
+            knowledge,
+            item: payee,
+        });
+        id
+    }
+
+    fn add_transaction(
+        &mut self,
+        account_id: Uuid,
+        date: &str,
+        amount: i64,
+        payee_name: Option<&str>,
+    ) -> TransactionSummary {
+        let payee_id = payee_name.map(|name| self.payee_id(name));
+        let knowledge = self.bump();
+
+        let mut transaction = TransactionSummary::new(
+            Uuid::new_v4().to_string(),
+            date.to_string(),
+            amount,
+            ynab_api::models::TransactionClearedStatus::Uncleared,
+            true,
+            account_id,
+            false,
+        );
+        transaction.payee_id = Some(payee_id);
+
+        self.adjust_balance(account_id, amount);
+        self.transactions.push(Versioned {
+            knowledge,
+            item: transaction.clone(),
+        });
+        transaction
+    }
+
+    fn adjust_balance(&mut self, account_id: Uuid, amount: i64) {
+        let knowledge = self.knowledge;
+
+        if let Some(account) = self.accounts.iter_mut().find(|a| a.item.id == account_id) {
+            account.knowledge = knowledge;
+            account.item.balance += amount;
+            account.item.cleared_balance += amount;
+        }
+    }
+
+    fn detail(&self, transaction: &TransactionSummary) -> TransactionDetail {
+        let account_name = self
+            .accounts
+            .iter()
+            .find(|a| a.item.id == transaction.account_id)
+            .map(|a| a.item.name.clone())
+            .unwrap_or_default();
+        let payee_name = self
+            .payees
+            .iter()
+            .find(|p| Some(Some(p.item.id)) == transaction.payee_id)
+            .map(|p| p.item.name.clone());
+
+        let mut detail = serde_json::to_value(transaction).expect("transaction serializes");
+        detail["account_name"] = json!(account_name);
+        detail["payee_name"] = json!(payee_name);
+        detail["subtransactions"] = json!([]);
+
+        serde_json::from_value(detail).expect("transaction detail deserializes")
+    }
+
+    fn handle(&mut self, method: &str, target: &str, body: &[u8]) -> Response {
+        let (path, query) = target.split_once('?').unwrap_or((target, ""));
+        let since = query
+            .split('&')
+            .filter_map(|pair| pair.split_once('='))
+            .find(|(key, _)| *key == "last_knowledge_of_server")
+            .and_then(|(_, value)| value.parse().ok())
+            .unwrap_or(0);
+
+        let segments = path
+            .trim_start_matches("/v1")
+            .trim_matches('/')
+            .split('/')
+            .collect::<Vec<_>>();
+
+        match (method, segments.as_slice()) {
+            ("GET", ["budgets"]) => ok(json!({ "data": { "budgets": [self.budget_summary()] } })),
+            ("GET", ["budgets", budget]) => self.for_budget(budget, |s| s.budget(since)),
+            ("GET", ["budgets", budget, "accounts"]) => self.for_budget(budget, |s| {
+                ok(json!({ "data": {
+                    "accounts": changed(&s.accounts, since),
+                    "server_knowledge": s.knowledge,
+                } }))
+            }),
+            ("POST", ["budgets", budget, "accounts"]) => {
+                self.for_budget(budget, |s| s.create_account(body))
+            }
+            ("GET", ["budgets", budget, "transactions"]) => self.for_budget(budget, |s| {
+                let transactions = changed(&s.transactions, since)
+                    .iter()
+                    .map(|t| s.detail(t))
+                    .collect::<Vec<_>>();
+                ok(json!({ "data": {
+                    "transactions": transactions,
+                    "server_knowledge": s.knowledge,
+                } }))
+            }),
+            ("POST", ["budgets", budget, "transactions"]) => {
+                self.for_budget(budget, |s| s.create_transactions(body))
+            }
+            ("PUT", ["budgets", budget, "transactions", id]) => {
+                self.for_budget(budget, |s| s.update_transaction(id, body))
+            }
+            _ => not_found(),
+        }
+    }
+
+    /// Runs a budget's handler, answering `404` for budgets other than the
+    /// mock's. `last-used` and `default` name it too.
+    fn for_budget(
+        &mut self,
+        budget: &str,
+        handler: impl FnOnce(&mut Self) -> Response,
+    ) -> Response {
+        if budget == "last-used" || budget == "default" || budget == self.budget_id.to_string() {
+            handler(self)
+        } else {
+            not_found()
+        }
+    }
+
+    fn budget_summary(&self) -> Value {
+        json!({
+            "id": self.budget_id,
+            "name": "Mock Budget",
+            "currency_format": self.currency,
+        })
+    }
+
+    fn budget(&self, since: i64) -> Response {
+        let mut budget = self.budget_summary();
+        budget["accounts"] = json!(changed(&self.accounts, since));
+        budget["payees"] = json!(changed(&self.payees, since));
+        budget["transactions"] = json!(changed(&self.transactions, since));
+        budget["category_groups"] = json!([]);
+        budget["categories"] = json!([]);
+        budget["subtransactions"] = json!([]);
+
+        ok(json!({ "data": { "budget": budget, "server_knowledge": self.knowledge } }))
+    }
+
+    fn create_account(&mut self, body: &[u8]) -> Response {
+        let wrapper: ynab_api::models::PostAccountWrapper = match parse(body) {
+            Ok(wrapper) => wrapper,
+            Err(response) => return response,
+        };
+
+        let account = self.add_account(
+            &wrapper.account.name,
+            wrapper.account.r#type,
+            wrapper.account.balance,
+        );
+
+        (201, json!({ "data": { "account": account } }))
+    }
+
+    fn create_transactions(&mut self, body: &[u8]) -> Response {
+        let wrapper: ynab_api::models::PostTransactionsWrapper = match parse(body) {
+            Ok(wrapper) => wrapper,
+            Err(response) => return response,
+        };
+
+        let single = wrapper.transaction.is_some();
+        let new = wrapper
+            .transaction
+            .map(|t| vec![*t])
+            .or(wrapper.transactions)
+            .unwrap_or_default();
+
+        let mut created = vec![];
+        for transaction in new {
+            let (Some(account_id), Some(date), Some(amount)) =
+                (transaction.account_id, transaction.date, transaction.amount)
+            else {
+                return bad_request("account_id, date and amount are required");
+            };
+
+            if !self.accounts.iter().any(|a| a.item.id == account_id) {
+                return not_found();
+            }
+
+            let payee_name = transaction.payee_name.flatten();
+            let mut summary =
+                self.add_transaction(account_id, &date, amount, payee_name.as_deref());
+            summary.memo = transaction.memo;
+            if let Some(cleared) = transaction.cleared {
+                summary.cleared = cleared;
+            }
+            self.replace(summary.clone());
+
+            created.push(self.detail(&summary));
+        }
+
+        let ids = created.iter().map(|t| t.id.clone()).collect::<Vec<_>>();
+        let mut data = json!({ "transaction_ids": ids, "server_knowledge": self.knowledge });
+        if single {
+            data["transaction"] = json!(created[0]);
+        } else {
+            data["transactions"] = json!(created);
+        }
+
+        (201, json!({ "data": data }))
+    }
+
+    fn update_transaction(&mut self, id: &str, body: &[u8]) -> Response {
+        let wrapper: ynab_api::models::PutTransactionWrapper = match parse(body) {
+            Ok(wrapper) => wrapper,
+            Err(response) => return response,
+        };
+        let changes = *wrapper.transaction;
+
+        let Some(mut transaction) = self
+            .transactions
+            .iter()
+            .find(|t| t.item.id == id && !t.item.deleted)
+            .map(|t| t.item.clone())
+        else {
+            return not_found();
+        };
+
+        if let Some(amount) = changes.amount {
+            self.adjust_balance(transaction.account_id, amount - transaction.amount);
+            transaction.amount = amount;
+        }
+        if let Some(date) = changes.date {
+            transaction.date = date;
+        }
+        if let Some(cleared) = changes.cleared {
+            transaction.cleared = cleared;
+        }
+        if let Some(approved) = changes.approved {
+            transaction.approved = approved;
+        }
+        if let Some(memo) = changes.memo {
+            transaction.memo = Some(memo);
+        }
+        if let Some(payee_name) = changes.payee_name.flatten() {
+            transaction.payee_id = Some(Some(self.payee_id(&payee_name)));
+        }
+
+        self.bump();
+        self.replace(transaction.clone());
+
+        ok(json!({ "data": { "transaction": self.detail(&transaction) } }))
+    }
+
+    /// Stores a changed transaction at the current server knowledge.
+    fn replace(&mut self, transaction: TransactionSummary) {
+        let knowledge = self.knowledge;
+
+        if let Some(entry) = self
+            .transactions
+            .iter_mut()
+            .find(|t| t.item.id == transaction.id)
+        {
+            entry.knowledge = knowledge;
+            entry.item = transaction;
+        }
+    }
+}
+
+/// The entities changed after a server knowledge.
+fn changed<T: Clone>(items: &[Versioned<T>], since: i64) -> Vec<T> {
+    items
+        .iter()
+        .filter(|v| v.knowledge > since)
+        .map(|v| v.item.clone())
+        .collect()
+}
+
+fn parse<T: DeserializeOwned>(body: &[u8]) -> Result<T, Response> {
+    serde_json::from_slice(body).map_err(|e| bad_request(&e.to_string()))
+}
+
+fn ok(body: impl Serialize) -> Response {
+    (200, json!(body))
+}
+
+fn error(status: u16, id: &str, name: &str, detail: &str) -> Response {
+    let error = ErrorResponse::new(ErrorDetail::new(
+        id.to_string(),
+        name.to_string(),
+        detail.to_string(),
+    ));
+    (status, json!(error))
+}
+
+fn bad_request(detail: &str) -> Response {
+    error(400, "400", "bad_request", detail)
+}
+
+fn not_found() -> Response {
+    error(404, "404.2", "resource_not_found", "Resource not found")
+}
+
+/// A mock YNAB API listening on a local port, with one budget.
+///
+/// The server runs on a background thread until the mock is dropped.
+pub struct MockYnab {
+    address: SocketAddr,
+    state: Arc<Mutex<State>>,
+    stopped: Arc<AtomicBool>,
+}
+
+impl MockYnab {
+    /// Starts a server with an empty budget in US dollars.
+    pub fn start() -> Self {
+        let listener = TcpListener::bind("127.0.0.1:0").expect("bind the mock YNAB server");
+        let address = listener.local_addr().expect("mock YNAB server address");
+
+        let state = Arc::new(Mutex::new(State {
+            budget_id: Uuid::new_v4(),
+            currency: currency_format("USD"),
+            knowledge: 0,
+            accounts: vec![],
+            payees: vec![],
+            transactions: vec![],
+            requests: vec![],
+        }));
+        let stopped = Arc::new(AtomicBool::new(false));
+
+        let server_state = state.clone();
+        let server_stopped = stopped.clone();
+        thread::spawn(move || {
+            for stream in listener.incoming() {
+                if server_stopped.load(Ordering::SeqCst) {
+                    break;
+                }
+
+                if let Ok(stream) = stream {
+                    // a client that hung up mid-request is not the test's concern
+                    let _ = serve(stream, &server_state);
+                }
+            }
+        });
+
+        MockYnab {
+            address,
+            state,
+            stopped,
+        }
+    }
+
+    /// The API base path, e.g. `http://127.0.0.1:40123/v1`.
+    pub fn base_path(&self) -> String {
+        format!("http://{}/v1", self.address)
+    }
+
+    /// The access token requests must be authorized with.
+    pub fn token(&self) -> &'static str {
+        TOKEN
+    }
+
+    pub fn budget_id(&self) -> Uuid {
+        self.state().budget_id
+    }
+
+    /// Sets the budget's currency by ISO code.
+    pub fn set_currency(&self, iso_code: &str) {
+        self.state().currency = currency_format(iso_code);
+    }
+
+    pub fn add_account(&self, name: &str) -> Account {
+        self.state().add_account(name, AccountType::OtherAsset, 0)
+    }
+
+    /// Adds a transaction, creating its payee if needed.
+    pub fn add_transaction(
+        &self,
+        account_id: Uuid,
+        date: &str,
+        amount: i64,
+        payee_name: &str,
+    ) -> TransactionDetail {
+        let mut state = self.state();
+        let transaction = state.add_transaction(account_id, date, amount, Some(payee_name));
+        state.detail(&transaction)
+    }
+
+    pub fn accounts(&self) -> Vec<Account> {
+        self.state()
+            .accounts
+            .iter()
+            .map(|a| a.item.clone())
+            .collect()
+    }
+
+    /// The transactions of the budget, with their account and payee names.
+    pub fn transactions(&self) -> Vec<TransactionDetail> {
+        let state = self.state();
+        state
+            .transactions
+            .iter()
+            .filter(|t| !t.item.deleted)
+            .map(|t| state.detail(&t.item))
+            .collect()
+    }
+
+    /// The requests served so far, as `METHOD /path?query`.
+    pub fn requests(&self) -> Vec<String> {
+        self.state().requests.clone()
+    }
+
+    fn state(&self) -> std::sync::MutexGuard<'_, State> {
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+impl Drop for MockYnab {
+    fn drop(&mut self) {
+        self.stopped.store(true, Ordering::SeqCst);
+        // wake up the server thread blocked on accept
+        let _ = TcpStream::connect(self.address);
+    }
+}
+
+fn currency_format(iso_code: &str) -> CurrencyFormat {
+    CurrencyFormat::new(
+        iso_code.to_string(),
+        "123,456.78".to_string(),
+        2,
+        ".".to_string(),
+        true,
+        ",".to_string(),
+        iso_code.to_string(),
+        true,
+    )
+}
+
+/// Reads one request from a connection and answers it, closing the
+/// connection afterwards.
+fn serve(stream: TcpStream, state: &Mutex<State>) -> std::io::Result<()> {
+    let mut reader = BufReader::new(stream.try_clone()?);
+
+    let mut request_line = String::new();
+    reader.read_line(&mut request_line)?;
+    let mut parts = request_line.split_whitespace();
+    let method = parts.next().unwrap_or_default().to_string();
+    let target = parts.next().unwrap_or_default().to_string();
+
+    let mut headers = HashMap::new();
+    loop {
+        let mut line = String::new();
+        if reader.read_line(&mut line)? == 0 || line.trim().is_empty() {
+            break;
+        }
+        if let Some((name, value)) = line.split_once(':') {
+            headers.insert(name.trim().to_lowercase(), value.trim().to_string());
+        }
+    }
+
+    let length = headers
+        .get("content-length")
+        .and_then(|l| l.parse().ok())
+        .unwrap_or(0);
+    let mut body = vec![0; length];
+    reader.read_exact(&mut body)?;
+
+    let mut state = state.lock().unwrap_or_else(|e| e.into_inner());
+    state.requests.push(format!("{method} {target}"));
+    let used = state.requests.len();
+
+    let authorized = headers.get("authorization").map(String::as_str)
+        == Some(format!("Bearer {TOKEN}").as_str());
+    let (status, body) = if authorized {
+        state.handle(&method, &target, &body)
+    } else {
+        error(401, "401", "unauthorized", "Unauthorized")
+    };
+    drop(state);
+
+    let body = body.to_string();
+    let response = format!(
+        "HTTP/1.1 {status} {}\r\nContent-Type: application/json; charset=utf-8\r\nContent-Length: {}\r\nX-Rate-Limit: {used}/{RATE_LIMIT}\r\nConnection: close\r\n\r\n{body}",
+        reason(status),
+        body.len()
+    );
+
+    let mut stream = stream;
+    stream.write_all(response.as_bytes())?;
+    stream.flush()
+}
+
+fn reason(status: u16) -> &'static str {
+    match status {
+        200 => "OK",
+        201 => "Created",
+        400 => "Bad Request",
+        401 => "Unauthorized",
+        _ => "Not Found",
+    }
+}